@@ -0,0 +1,89 @@
+use maths::{Point, Vec2};
+
+use super::camera::{Camera, Orientation};
+
+/// Every field starts at its Camera::default value
+///
+/// ```
+/// use camera2d_ggez::{Camera, CameraBuilder};
+///
+/// assert_eq!(CameraBuilder::new().build(), Camera::default());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CameraBuilder {
+    camera: Camera,
+}
+
+impl CameraBuilder {
+    pub fn new() -> Self {
+        CameraBuilder::default()
+    }
+
+    pub fn offset<P>(mut self, offset: P) -> Self
+    where
+        P: Into<Point>,
+    {
+        self.camera.offset = offset.into();
+        self
+    }
+
+    pub fn rotation(mut self, rotation: f64) -> Self {
+        self.camera.rotation = rotation;
+        self
+    }
+
+    pub fn scale<V>(mut self, scale: V) -> Self
+    where
+        V: Into<Vec2>,
+    {
+        self.camera.scale = scale.into();
+        self
+    }
+
+    pub fn position<P>(mut self, position: P) -> Self
+    where
+        P: Into<Point>,
+    {
+        self.camera.position = position.into();
+        self
+    }
+
+    pub fn screen_size<V>(mut self, screen_size: V) -> Self
+    where
+        V: Into<Vec2>,
+    {
+        self.camera.screen_size = screen_size.into();
+        self
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.camera.orientation = orientation;
+        self
+    }
+
+    pub fn build(mut self) -> Camera {
+        self.camera.refresh_cache();
+        self.camera
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_matches_new() {
+        let built = CameraBuilder::new()
+            .offset((960., 540.))
+            .rotation(0.5)
+            .scale((2., 3.))
+            .position((-10., 25.))
+            .screen_size((1280., 720.))
+            .build();
+
+        assert_eq!(
+            built,
+            Camera::new((960., 540.), 0.5, (2., 3.), (-10., 25.), (1280., 720.))
+        );
+    }
+}
@@ -1,19 +1,173 @@
+#[cfg(feature = "ggez")]
 use ggez::{
-    glam::{f64, Mat4, Vec3},
-    graphics::DrawParam,
-    Context,
+    graphics::{self, Canvas, Color, DrawMode, DrawParam, Drawable, Mesh, MeshBuilder},
+    mint, Context, GameResult,
 };
+use glam::{Mat4, Vec3};
 use maths::{Point, Rect, Vec2};
+use std::{
+    f64::consts::{PI, TAU},
+    fmt,
+};
+
+use super::{
+    builder::CameraBuilder,
+    easing::{Easing, EasingFn},
+    follow::DynamicFollowConfig,
+    shake::{ShakeConfig, ShakeState},
+    transform::Transform,
+};
+
+// Number of named offsets set_offset_channel can hold, a fixed table keeps Camera Copy
+pub const OFFSET_CHANNELS: usize = 8;
 
-use super::transform::Transform;
+// Smallest scale magnitude set_zoom allows, a zero scale would make the view matrix singular
+const MIN_SCALE: f64 = 1e-5;
 
+/// The methods only invalidate the cached view matrix and its inverse, and fill the cache
+/// again once they're done. The cache is only used while the fields it was computed from are
+/// unchanged, so the fields can be written directly.
+/// screen_size and every screen coordinate taken or returned by the methods are logical
+/// pixels, ggez reports the mouse and the drawable size in physical pixels so divide those
+/// by dpi_scale first. Only to_matrix and the matrices built from it output physical pixels,
+/// the logical ones multiplied by dpi_scale
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Camera {
     pub offset: Point,
     pub rotation: f64,
     pub scale: Vec2,
     pub position: Point,
     pub screen_size: Vec2,
+    pub min_scale: Option<Vec2>,
+    pub max_scale: Option<Vec2>,
+    // (min, max) in radians, applied by rotate and set_rotation
+    pub rotation_limits: Option<(f64, f64)>,
+    pub shake: ShakeState,
+    pub bounds: Option<Rect>,
+    // (min, max) of the position, applied after the bounds, use infinities to leave an axis free
+    pub position_limits: Option<(Point, Point)>,
+    pub design_resolution: Option<Vec2>,
+    // Region of the window the camera renders into, for split screen, None is the whole window.
+    // Drawing isn't clipped to it, set the canvas scissor rect to letterbox_viewport for that
+    pub viewport: Option<Rect>,
+    // Camera position change in world units per second
+    pub velocity: Vec2,
+    // Exponential velocity falloff per second, 0 keeps the velocity forever
+    pub damping: f64,
+    // Maps world coordinates 1:1 to pixels whatever the other fields are, for HUD passes
+    pub screen_space: bool,
+    // Rounds the translation to whole pixels in to_matrix, only for axis aligned cameras
+    pub pixel_snap: bool,
+    /// Quantizes the translation to stabilize_step pixels in to_matrix, even when rotated.
+    /// Trades a positional error of up to half a step for no sub pixel shimmer
+    pub stabilize: bool,
+    pub stabilize_step: f64,
+    pub orientation: Orientation,
+    /// Physical pixels per logical pixel, 2 on most HiDPI displays
+    pub dpi_scale: f64,
+    // Named screen offsets added on top of `offset`, see set_offset_channel. Not serialized,
+    // the names can't be deserialized as &'static str
+    #[cfg_attr(feature = "serde", serde(skip))]
+    offset_channels: [Option<(&'static str, Point)>; OFFSET_CHANNELS],
+    #[cfg_attr(feature = "serde", serde(skip))]
+    recenter: Option<Recenter>,
+    // View matrix and its inverse with the fields they came from, see view_matrix
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cache: Option<(ViewInputs, Mat4, Mat4)>,
+}
+
+// The point that keeps its place on screen while zooming
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZoomAnchor {
+    // Center of the content area
+    Center,
+    // In window pixels, like the mouse position
+    ScreenPoint(Point),
+    WorldPoint(Point),
+    // The camera position, which sits at the offset on screen
+    Origin,
+}
+
+// Direction the world y axis points to on screen
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    // ggez's convention
+    #[default]
+    YDown,
+    // The view is mirrored about the screen center, world y then grows upwards and a
+    // positive rotation turns the view counter-clockwise
+    YUp,
+}
+
+impl Orientation {
+    // -1 when the mirror flips the screen y axis
+    fn screen_up_sign(&self) -> f64 {
+        match self {
+            Orientation::YDown => 1.,
+            Orientation::YUp => -1.,
+        }
+    }
+}
+
+// Transition started by recenter_over
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Recenter {
+    start: Point,
+    target: Point,
+    duration: f64,
+    elapsed: f64,
+}
+
+// Every field the view matrix is computed from, the cache is valid while they all match
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ViewInputs {
+    offset: Point,
+    offset_channels: [Option<(&'static str, Point)>; OFFSET_CHANNELS],
+    rotation: f64,
+    scale: Vec2,
+    position: Point,
+    screen_size: Vec2,
+    shake: ShakeState,
+    design_resolution: Option<Vec2>,
+    viewport: Option<Rect>,
+    screen_space: bool,
+    pixel_snap: bool,
+    stabilize: bool,
+    stabilize_step: f64,
+    orientation: Orientation,
+    dpi_scale: f64,
+}
+
+// Every field but the cache, it's derived from the others
+impl PartialEq for Camera {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+            && self.rotation == other.rotation
+            && self.scale == other.scale
+            && self.position == other.position
+            && self.screen_size == other.screen_size
+            && self.min_scale == other.min_scale
+            && self.max_scale == other.max_scale
+            && self.rotation_limits == other.rotation_limits
+            && self.shake == other.shake
+            && self.bounds == other.bounds
+            && self.position_limits == other.position_limits
+            && self.design_resolution == other.design_resolution
+            && self.viewport == other.viewport
+            && self.velocity == other.velocity
+            && self.damping == other.damping
+            && self.screen_space == other.screen_space
+            && self.pixel_snap == other.pixel_snap
+            && self.stabilize == other.stabilize
+            && self.stabilize_step == other.stabilize_step
+            && self.orientation == other.orientation
+            && self.dpi_scale == other.dpi_scale
+            && self.offset_channels == other.offset_channels
+            && self.recenter == other.recenter
+    }
 }
 
 impl Default for Camera {
@@ -24,7 +178,48 @@ impl Default for Camera {
             scale: Vec2::ONE,
             position: Point::ZERO,
             screen_size: Vec2::new(1920., 1080.),
+            min_scale: None,
+            max_scale: None,
+            rotation_limits: None,
+            shake: ShakeState::default(),
+            bounds: None,
+            position_limits: None,
+            design_resolution: None,
+            viewport: None,
+            velocity: Vec2::ZERO,
+            damping: 4.,
+            screen_space: false,
+            pixel_snap: false,
+            stabilize: false,
+            stabilize_step: 0.125,
+            orientation: Orientation::YDown,
+            dpi_scale: 1.,
+            offset_channels: [None; OFFSET_CHANNELS],
+            recenter: None,
+            cache: None,
+        }
+    }
+}
+
+// Short summary for logs, e.g. Camera(pos=(10.0,-5.0) zoom=2.0 rot=45° screen=1920x1080)
+impl fmt::Display for Camera {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Camera(pos=({:?},{:?}) ",
+            self.position.x, self.position.y
+        )?;
+        match self.uniform_zoom() {
+            Some(zoom) => write!(f, "zoom={zoom:?} ")?,
+            None => write!(f, "zoom=({:?},{:?}) ", self.scale.x, self.scale.y)?,
         }
+        // Rounded so 45 degrees doesn't print as 45.00000000000001
+        let degrees = (self.rotation.to_degrees() * 100.).round() / 100.;
+        write!(
+            f,
+            "rot={degrees}° screen={}x{})",
+            self.screen_size.x, self.screen_size.y
+        )
     }
 }
 
@@ -40,16 +235,308 @@ impl Camera {
             scale: scale.into(),
             position: position.into(),
             screen_size: screen_size.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn screen_space<V>(screen_size: V) -> Self
+    where
+        V: Into<Vec2>,
+    {
+        Camera {
+            screen_size: screen_size.into(),
+            screen_space: true,
+            ..Default::default()
+        }
+    }
+
+    // The rect is fit (contained) in the screen with a uniform zoom and centered,
+    // when the aspect ratios differ more of the world is visible along the other axis
+    pub fn from_view_rect<V>(rect: Rect, screen_size: V) -> Self
+    where
+        V: Into<Vec2>,
+    {
+        let mut camera = Camera {
+            screen_size: screen_size.into(),
+            ..Default::default()
+        };
+        camera.fit_rect(rect, 0.);
+        camera
+    }
+
+    pub fn builder() -> CameraBuilder {
+        CameraBuilder::new()
+    }
+
+    /// Fluent setters for quick setups, each one only sets its own field
+    ///
+    /// ```
+    /// use camera2d_ggez::Camera;
+    ///
+    /// let camera = Camera::default()
+    ///     .with_position((100., 50.))
+    ///     .with_zoom(2.)
+    ///     .with_rotation(0.5)
+    ///     .with_offset((640., 360.))
+    ///     .with_screen_size((1280., 720.));
+    ///
+    /// assert_eq!((camera.position.x, camera.position.y), (100., 50.));
+    /// assert_eq!((camera.scale.x, camera.scale.y), (2., 2.));
+    /// assert_eq!(camera.rotation, 0.5);
+    /// assert_eq!((camera.offset.x, camera.offset.y), (640., 360.));
+    /// assert_eq!((camera.screen_size.x, camera.screen_size.y), (1280., 720.));
+    /// ```
+    pub fn with_position<P>(mut self, position: P) -> Self
+    where
+        P: Into<Point>,
+    {
+        self.position = position.into();
+        self.apply_position_limits();
+        self.refresh_cache();
+        self
+    }
+
+    // Also takes a scalar for a uniform zoom, clamped like set_zoom
+    pub fn with_zoom<V>(mut self, scale: V) -> Self
+    where
+        V: Into<Vec2>,
+    {
+        self.set_zoom(scale);
+        self
+    }
+
+    pub fn with_rotation(mut self, rotation: f64) -> Self {
+        self.rotation = rotation;
+        self.refresh_cache();
+        self
+    }
+
+    pub fn with_offset<P>(mut self, offset: P) -> Self
+    where
+        P: Into<Point>,
+    {
+        self.offset = offset.into();
+        self.refresh_cache();
+        self
+    }
+
+    pub fn with_screen_size<V>(mut self, screen_size: V) -> Self
+    where
+        V: Into<Vec2>,
+    {
+        self.screen_size = screen_size.into();
+        self.refresh_cache();
+        self
+    }
+
+    // Copy of the camera for rendering to a target of another size, like an offscreen canvas,
+    // for a single pass while this camera keeps the window size
+    pub fn with_screen_size_scoped<V>(&self, screen_size: V) -> Camera
+    where
+        V: Into<Vec2>,
+    {
+        self.with_screen_size(screen_size)
+    }
+
+    // Rotation goes along the shortest path, fields that aren't interpolated are taken from `a`
+    // until `t` reaches 1 where the result is `b`. A recenter_over going on isn't carried over
+    pub fn lerp(a: &Camera, b: &Camera, t: f64) -> Camera {
+        if t > 0. && t < 1. {
+            return Camera::blend(a, b, t);
         }
+
+        let mut camera = if t >= 1. { *b } else { *a };
+        camera.recenter = None;
+        camera.refresh_cache();
+        camera
+    }
+
+    // lerp without the clamping, a `t` outside of 0..1 extrapolates, for overshooting curves
+    pub(crate) fn blend(a: &Camera, b: &Camera, t: f64) -> Camera {
+        let mix = |from: f64, to: f64| from + (to - from) * t;
+        let rotation_delta = wrap_angle(b.rotation - a.rotation);
+
+        let mut camera = Camera {
+            offset: Point::new(mix(a.offset.x, b.offset.x), mix(a.offset.y, b.offset.y)),
+            rotation: a.rotation + rotation_delta * t,
+            scale: Vec2::new(mix(a.scale.x, b.scale.x), mix(a.scale.y, b.scale.y)),
+            position: Point::new(
+                mix(a.position.x, b.position.x),
+                mix(a.position.y, b.position.y),
+            ),
+            screen_size: Vec2::new(
+                mix(a.screen_size.x, b.screen_size.x),
+                mix(a.screen_size.y, b.screen_size.y),
+            ),
+            recenter: None,
+            ..*a
+        };
+        camera.refresh_cache();
+        camera
+    }
+
+    pub fn reset(&mut self) {
+        *self = Camera::default();
+    }
+
+    pub fn reset_keep_screen_size(&mut self) {
+        *self = Camera {
+            screen_size: self.screen_size,
+            ..Default::default()
+        };
     }
+
+    /// Rendering matrix, maps world coordinates to physical pixels
     pub fn to_matrix(&self) -> Mat4 {
-        let (sinr, cosr) = self.rotation.sin_cos();
-        let m00 = cosr * self.scale.x;
-        let m01 = -sinr * self.scale.y;
-        let m10 = sinr * self.scale.x;
-        let m11 = cosr * self.scale.y;
-        let m03 = self.position.x * (-m00) - self.position.y * m01 + self.offset.x;
-        let m13 = self.position.y * (-m11) - self.position.x * m10 + self.offset.y;
+        self.to_physical(self.view_matrix())
+    }
+
+    /// Maps physical pixels back to world coordinates, from the cache like to_matrix. A singular
+    /// view (zero sized window) gives NaN or infinite entries, see try_screen_to_world_coords
+    pub fn to_inverse_matrix(&self) -> Mat4 {
+        let inverse = self.inverse_matrix();
+        if self.dpi_scale == 1. {
+            return inverse;
+        }
+
+        let dpi_scale = 1. / self.dpi_scale as f32;
+        inverse.mul_mat4(&Mat4::from_scale(Vec3::new(dpi_scale, dpi_scale, 1.)))
+    }
+
+    /// to_matrix as 16 floats in column-major order, each group of 4 is a column and the
+    /// translation is in the last one, the layout GPU uniform buffers expect
+    pub fn to_matrix_array(&self) -> [f32; 16] {
+        self.to_matrix().to_cols_array()
+    }
+
+    /// Inverse of to_matrix, also column-major
+    pub fn to_inverse_matrix_array(&self) -> [f32; 16] {
+        self.to_inverse_matrix().to_cols_array()
+    }
+
+    // Maps world coordinates to logical pixels, used by all the coordinate conversions.
+    // Computed on the fly while the cache is invalidated or stale
+    fn view_matrix(&self) -> Mat4 {
+        match self.cache {
+            Some((inputs, matrix, _)) if inputs == self.view_inputs() => matrix,
+            _ => self.compute_matrix(self.position),
+        }
+    }
+
+    fn to_physical(&self, matrix: Mat4) -> Mat4 {
+        if self.dpi_scale == 1. {
+            return matrix;
+        }
+
+        let dpi_scale = self.dpi_scale as f32;
+        Mat4::from_scale(Vec3::new(dpi_scale, dpi_scale, 1.)).mul_mat4(&matrix)
+    }
+
+    fn inverse_matrix(&self) -> Mat4 {
+        match self.cache {
+            Some((inputs, _, inverse)) if inputs == self.view_inputs() => inverse,
+            _ => self.compute_matrix(self.position).inverse(),
+        }
+    }
+
+    // Called once at the end of the methods, after writing to the fields directly it makes
+    // the conversions use the cache again instead of computing the matrices on the fly
+    pub fn refresh_cache(&mut self) {
+        let matrix = self.compute_matrix(self.position);
+        self.cache = Some((self.view_inputs(), matrix, matrix.inverse()));
+    }
+
+    fn view_inputs(&self) -> ViewInputs {
+        ViewInputs {
+            offset: self.offset,
+            offset_channels: self.offset_channels,
+            rotation: self.rotation,
+            scale: self.scale,
+            position: self.position,
+            screen_size: self.screen_size,
+            shake: self.shake,
+            design_resolution: self.design_resolution,
+            viewport: self.viewport,
+            screen_space: self.screen_space,
+            pixel_snap: self.pixel_snap,
+            stabilize: self.stabilize,
+            stabilize_step: self.stabilize_step,
+            orientation: self.orientation,
+            dpi_scale: self.dpi_scale,
+        }
+    }
+
+    // Called by the steps of the methods that change the view, until the final refresh_cache
+    fn invalidate_cache(&mut self) {
+        self.cache = None;
+    }
+
+    // Depth 1 is the regular camera, lower depths follow the camera position less
+    // and depth 0 doesn't move at all, zoom and rotation are always fully applied
+    pub fn to_matrix_parallax(&self, depth: f64) -> Mat4 {
+        let position = Point::new(self.position.x * depth, self.position.y * depth);
+        self.to_physical(self.compute_matrix(position))
+    }
+
+    fn compute_matrix(&self, position: Point) -> Mat4 {
+        if self.screen_space {
+            return Mat4::IDENTITY;
+        }
+
+        let mut offset = self.offset;
+        for (_, channel) in self.offset_channels.iter().flatten() {
+            offset.x += channel.x;
+            offset.y += channel.y;
+        }
+        if let Some(shake) = self.shake.offset() {
+            offset.x += shake.x;
+            offset.y += shake.y;
+        }
+
+        let mut rotation = self.rotation;
+        if let Some(shake) = self.shake.rotation() {
+            rotation += shake;
+        }
+
+        // The fields can be written directly, so a zero scale can still get here
+        let floor = |scale: f64| {
+            if scale.abs() < MIN_SCALE {
+                MIN_SCALE.copysign(scale)
+            } else {
+                scale
+            }
+        };
+        let scale = Vec2::new(floor(self.scale.x), floor(self.scale.y));
+
+        let (sinr, cosr) = rotation.sin_cos();
+        let m00 = cosr * scale.x;
+        let m01 = -sinr * scale.y;
+        let m10 = sinr * scale.x;
+        let m11 = cosr * scale.y;
+        let m03 = position.x * (-m00) - position.y * m01 + offset.x;
+        let m13 = position.y * (-m11) - position.x * m10 + offset.y;
+
+        let (fit, bars) = self.letterbox_fit();
+        let (m00, m01, mut m10, mut m11) = (m00 * fit, m01 * fit, m10 * fit, m11 * fit);
+        let (mut m03, mut m13) = (m03 * fit + bars.x, m13 * fit + bars.y);
+
+        // Mirrored about the horizontal center line of the content area
+        if self.orientation == Orientation::YUp {
+            let mirror = bars.y * 2. + self.logical_size().y * fit;
+            m10 = -m10;
+            m11 = -m11;
+            m13 = mirror - m13;
+        }
+
+        // Snapped on physical pixels, that's where the sprites end up
+        if self.pixel_snap && is_axis_aligned(rotation) {
+            m03 = (m03 * self.dpi_scale).round() / self.dpi_scale;
+            m13 = (m13 * self.dpi_scale).round() / self.dpi_scale;
+        } else if self.stabilize && self.stabilize_step > 0. {
+            let step = self.stabilize_step / self.dpi_scale;
+            m03 = (m03 / step).round() * step;
+            m13 = (m13 / step).round() * step;
+        }
 
         Mat4::from_cols_array(&[
             m00 as f32, m01 as f32, 0.0, m03 as f32, //
@@ -60,6 +547,96 @@ impl Camera {
         .transpose()
     }
 
+    // Uniform scale and centering offset used to fit the design resolution in the viewport,
+    // only the viewport origin when no design resolution is set
+    fn letterbox_fit(&self) -> (f64, Point) {
+        let (origin, size) = self.viewport_region();
+        let Some(design) = self.design_resolution else {
+            return (1., origin);
+        };
+
+        let fit = (size.x / design.x).min(size.y / design.y);
+        let bars = Point::new(
+            origin.x + (size.x - design.x * fit) * 0.5,
+            origin.y + (size.y - design.y * fit) * 0.5,
+        );
+
+        (fit, bars)
+    }
+
+    // Top-left and size of the viewport in window pixels
+    fn viewport_region(&self) -> (Point, Vec2) {
+        match self.viewport {
+            Some(viewport) => (
+                viewport.aa_topleft(),
+                Vec2::new(viewport.width(), viewport.height()),
+            ),
+            None => (Point::ZERO, self.screen_size),
+        }
+    }
+
+    // Size of the screen the camera works in, the design resolution when one is set
+    fn logical_size(&self) -> Vec2 {
+        self.design_resolution
+            .unwrap_or_else(|| self.viewport_region().1)
+    }
+
+    // Center of the area the content is drawn in, in window pixels
+    pub fn screen_center(&self) -> Point {
+        let (fit, bars) = self.letterbox_fit();
+        let size = self.logical_size();
+
+        Point::new(bars.x + size.x * fit * 0.5, bars.y + size.y * fit * 0.5)
+    }
+
+    // Area of the window the content is drawn in, everything outside of it is letterboxing
+    pub fn letterbox_viewport(&self) -> Rect {
+        let (fit, bars) = self.letterbox_fit();
+        let size = self.logical_size();
+
+        Rect::new(bars, Vec2::new(size.x * fit, size.y * fit), 0.)
+    }
+
+    /// Logical size, ggez's physical drawable size divided by the dpi scale
+    pub fn set_screen_size<V>(&mut self, screen_size: V)
+    where
+        V: Into<Vec2>,
+    {
+        self.screen_size = screen_size.into();
+        self.refresh_cache();
+    }
+
+    // In window pixels, the screen coordinates of all the methods stay window coordinates
+    pub fn set_viewport(&mut self, viewport: Rect) {
+        self.viewport = Some(viewport);
+        self.refresh_cache();
+    }
+
+    pub fn clear_viewport(&mut self) {
+        self.viewport = None;
+        self.refresh_cache();
+    }
+
+    /// Physical pixels per logical pixel, ggez's window scale factor
+    pub fn set_dpi_scale(&mut self, dpi_scale: f64) {
+        self.dpi_scale = dpi_scale;
+        self.refresh_cache();
+    }
+
+    // Content is then scaled uniformly to fit the window, so it never stretches
+    pub fn set_design_resolution<V>(&mut self, resolution: V)
+    where
+        V: Into<Vec2>,
+    {
+        self.design_resolution = Some(resolution.into());
+        self.refresh_cache();
+    }
+
+    pub fn clear_design_resolution(&mut self) {
+        self.design_resolution = None;
+        self.refresh_cache();
+    }
+
     pub fn apply_matrix<T>(&self, object: T) -> Mat4
     where
         T: Into<Transform>,
@@ -69,139 +646,4228 @@ impl Camera {
         self.to_matrix().mul_mat4(&object.to_matrix())
     }
 
+    // For picture in picture, the child's screen pixels are taken as world units of this
+    // camera: world -> child screen -> parent screen. Only this camera's dpi scale is applied
+    pub fn nested(&self, child: &Camera) -> Mat4 {
+        self.to_matrix().mul_mat4(&child.view_matrix())
+    }
+
+    // apply_matrix for an object on a parallax layer, see to_matrix_parallax for the depth
+    pub fn layer_matrix<T>(&self, depth: f64, object: T) -> Mat4
+    where
+        T: Into<Transform>,
+    {
+        let object: Transform = object.into();
+
+        self.to_matrix_parallax(depth).mul_mat4(&object.to_matrix())
+    }
+
+    /// World coordinates to logical pixels, multiply by dpi_scale for physical ones
     pub fn world_to_screen_coords<P>(&self, point: P) -> Point
     where
         P: Into<Point>,
     {
         let point: Point = point.into();
         let point = Vec3::new(point.x as f32, point.y as f32, 0.);
-        let screen_point = self.to_matrix().transform_point3(point);
+        let screen_point = self.view_matrix().transform_point3(point);
 
         Point::new(screen_point.x as f64, screen_point.y as f64)
     }
 
-    pub fn screen_to_world_coords<P>(&self, point: P) -> Point
+    // Pixel distance between the two world points as they are drawn right now
+    pub fn screen_distance<P>(&self, a: P, b: P) -> f64
     where
         P: Into<Point>,
     {
-        let inverse_matrix = self.to_matrix().inverse();
-        let point: Point = point.into();
-        let point = Vec3::new(point.x as f32, point.y as f32, 0.);
-        let world_point = inverse_matrix.transform_point3(point);
-
-        Point::new(world_point.x as f64, world_point.y as f64)
+        maths::get_distance(
+            &self.world_to_screen_coords(a),
+            &self.world_to_screen_coords(b),
+        )
     }
 
-    // Clockwise rotation
-    pub fn world_view(&self) -> Rect {
-        let topleft = self.screen_to_world_coords(0.);
-
-        Rect::new(
-            topleft,
-            maths::get_distance(
-                &topleft,
-                &self.screen_to_world_coords((self.screen_size.x, 0.)),
-            ),
-            0.,
-        )
+    pub fn world_distance<P>(&self, a: P, b: P) -> f64
+    where
+        P: Into<Point>,
+    {
+        maths::get_distance(&a.into(), &b.into())
     }
 
-    pub fn set_position<P>(&mut self, point: P)
+    // World to screen and back, should give the point back up to the f32 precision of the
+    // matrices. Meant for checking the precision at the coordinates and zooms a game uses
+    pub fn round_trip_world<P>(&self, point: P) -> Point
     where
         P: Into<Point>,
     {
-        let point: Point = point.into();
-        self.position.x = point.x;
-        self.position.y = point.y;
+        self.screen_to_world_coords(self.world_to_screen_coords(point))
     }
 
-    pub fn set_offset<P>(&mut self, point: P)
+    // Where the world (0, 0) is on screen
+    pub fn origin_on_screen(&self) -> Point {
+        self.world_to_screen_coords(Point::ZERO)
+    }
+
+    pub fn origin_visible(&self) -> bool {
+        self.is_point_visible(Point::ZERO)
+    }
+
+    /// Logical pixels to world coordinates, divide ggez's physical mouse position by dpi_scale
+    /// first. The scale is floored to MIN_SCALE in the matrix so a zero zoom stays invertible,
+    /// NaN is still possible with a zero sized window, see try_screen_to_world_coords
+    pub fn screen_to_world_coords<P>(&self, point: P) -> Point
     where
         P: Into<Point>,
     {
+        let inverse_matrix = self.inverse_matrix();
         let point: Point = point.into();
-        self.offset.x = point.x * self.scale.x;
-        self.offset.y = point.y * self.scale.y;
+        let point = Vec3::new(point.x as f32, point.y as f32, 0.);
+        let world_point = inverse_matrix.transform_point3(point);
+
+        Point::new(world_point.x as f64, world_point.y as f64)
     }
 
-    pub fn move_by_world_coords<P>(&mut self, delta: P)
+    // None when the view can't be inverted, which happens with a zero scale (set_zoom floors it
+    // but the field can be written directly), a zero sized window and a design resolution,
+    // or NaN fields. screen_to_world_coords gives the floored scale's result for a zero scale
+    pub fn try_screen_to_world_coords<P>(&self, point: P) -> Option<Point>
     where
         P: Into<Point>,
     {
-        let delta: Point = delta.into();
-        self.position.x -= delta.x;
-        self.position.y -= delta.y;
+        let zero_scale = self.scale.x.abs() < MIN_SCALE || self.scale.y.abs() < MIN_SCALE;
+        if zero_scale && !self.screen_space {
+            return None;
+        }
+
+        let determinant = self.view_matrix().determinant();
+        if determinant.is_nan() || determinant.abs() < 1e-12 {
+            return None;
+        }
+
+        Some(self.screen_to_world_coords(point))
     }
 
-    pub fn move_by_screen_coords<P>(&mut self, delta: P)
+    // None when the point is in a letterbox bar or outside the viewport, where there is no world
+    pub fn screen_to_world_coords_clamped<P>(&self, point: P) -> Option<Point>
     where
         P: Into<Point>,
     {
-        let delta: Point = delta.into();
-        self.position.x -= delta.x / self.scale.x;
-        self.position.y -= delta.y / self.scale.y;
+        let point: Point = point.into();
+        let content = self.letterbox_viewport();
+        let topleft = content.aa_topleft();
+
+        let inside = point.x >= topleft.x
+            && point.x <= topleft.x + content.width()
+            && point.y >= topleft.y
+            && point.y <= topleft.y + content.height();
+        if !inside {
+            return None;
+        }
+
+        self.try_screen_to_world_coords(point)
     }
 
-    pub fn get_zoom(&self) -> Vec2 {
-        self.scale
+    pub fn world_to_screen(&self, x: f64, y: f64) -> (f64, f64) {
+        let screen_point = self
+            .view_matrix()
+            .transform_point3(Vec3::new(x as f32, y as f32, 0.));
+
+        (screen_point.x as f64, screen_point.y as f64)
     }
 
-    pub fn set_zoom<V>(&mut self, scale: V)
-    where
-        V: Into<Vec2>,
-    {
-        self.scale = scale.into();
+    pub fn screen_to_world(&self, x: f64, y: f64) -> (f64, f64) {
+        let world_point = self
+            .inverse_matrix()
+            .transform_point3(Vec3::new(x as f32, y as f32, 0.));
+
+        (world_point.x as f64, world_point.y as f64)
     }
 
-    pub fn zoom<V>(&mut self, factor: V)
-    where
-        V: Into<Vec2>,
-    {
-        let factor: Vec2 = factor.into();
-        self.scale.x *= factor.x;
-        self.scale.y *= factor.y;
+    // Logical pixels covered by one world unit along each world axis, including the letterbox fit
+    pub fn world_to_screen_scale(&self) -> Vec2 {
+        let matrix = self.view_matrix();
+
+        Vec2::new(
+            (matrix.x_axis.x as f64).hypot(matrix.x_axis.y as f64),
+            (matrix.y_axis.x as f64).hypot(matrix.y_axis.y as f64),
+        )
+    }
+
+    pub fn screen_to_world_scale(&self) -> Vec2 {
+        let scale = self.world_to_screen_scale();
+
+        Vec2::new(1. / scale.x, 1. / scale.y)
     }
 
-    pub fn zoom_center<V>(&mut self, ctx: &Context, factor: V)
+    // Only the rotation and scale are applied, not the translation, for directions and deltas
+    pub fn world_to_screen_vector<V>(&self, vector: V) -> Vec2
     where
         V: Into<Vec2>,
     {
-        let factor: Vec2 = factor.into();
-        let screen_rect: Point = ctx.gfx.drawable_size().into();
-        let screen_center = screen_rect * 0.5;
+        let vector: Vec2 = vector.into();
+        let vector = Vec3::new(vector.x as f32, vector.y as f32, 0.);
+        let screen_vector = self.view_matrix().transform_vector3(vector);
 
-        let world_center = self.screen_to_world_coords(screen_center);
-        self.position.x = world_center.x - (world_center.x - self.position.x) / factor.x;
-        self.position.y = world_center.y - (world_center.y - self.position.y) / factor.y;
-        self.scale.x *= factor.x;
-        self.scale.y *= factor.y;
+        Vec2::new(screen_vector.x as f64, screen_vector.y as f64)
     }
 
-    pub fn zoom_at_screen_coords<P, V>(&mut self, point: P, factor: V)
+    pub fn screen_to_world_vector<V>(&self, vector: V) -> Vec2
     where
-        P: Into<Point>,
         V: Into<Vec2>,
     {
-        let point: Point = point.into();
-        let factor: Vec2 = factor.into();
-        let world_center = self.screen_to_world_coords(point);
-        self.position.x = world_center.x - (world_center.x - self.position.x) / factor.x;
-        self.position.y = world_center.y - (world_center.y - self.position.y) / factor.y;
-        self.scale.x *= factor.x;
-        self.scale.y *= factor.y;
+        let vector: Vec2 = vector.into();
+        let vector = Vec3::new(vector.x as f32, vector.y as f32, 0.);
+        let world_vector = self.inverse_matrix().transform_vector3(vector);
+
+        Vec2::new(world_vector.x as f64, world_vector.y as f64)
     }
 
-    pub fn rotate(&mut self, angle: f64) {
-        self.rotation += angle;
+    pub fn world_to_screen_coords_many(&self, points: &[Point]) -> Vec<Point> {
+        let matrix = self.view_matrix();
+
+        points
+            .iter()
+            .map(|point| {
+                let screen_point =
+                    matrix.transform_point3(Vec3::new(point.x as f32, point.y as f32, 0.));
+                Point::new(screen_point.x as f64, screen_point.y as f64)
+            })
+            .collect()
     }
 
-    pub fn set_rotation(&mut self, angle: f64) {
-        self.rotation = angle;
+    // Keeps the vertex order, so the winding is preserved
+    pub fn world_polygon_to_screen(&self, points: &[Point]) -> Vec<Point> {
+        self.world_to_screen_coords_many(points)
     }
-}
 
-impl From<Camera> for DrawParam {
-    fn from(value: Camera) -> Self {
-        DrawParam::default().transform(value.to_matrix())
+    pub fn screen_to_world_coords_many(&self, points: &[Point]) -> Vec<Point> {
+        let inverse_matrix = self.inverse_matrix();
+
+        points
+            .iter()
+            .map(|point| {
+                let world_point =
+                    inverse_matrix.transform_point3(Vec3::new(point.x as f32, point.y as f32, 0.));
+                Point::new(world_point.x as f64, world_point.y as f64)
+            })
+            .collect()
+    }
+
+    /// Axis aligned bounding box of the visible area in world space,
+    /// when the camera is rotated it covers more than what is actually visible
+    pub fn world_view(&self) -> Rect {
+        let (min, max) = self.world_aabb();
+
+        Rect::new(min, Vec2::new(max.x - min.x, max.y - min.y), 0.)
+    }
+
+    /// (min, max) corners of world_view, taken over all four screen corners
+    pub fn world_aabb(&self) -> (Point, Point) {
+        bounding_box(&self.world_frustum_corners())
+    }
+
+    // Inclusive (min_x, min_y, max_x, max_y) indices of the tiles covering world_view, tile
+    // (0, 0) spanning 0..tile_size. Negative coordinates are floored, so -0.5 is in tile -1
+    pub fn visible_tile_range<V>(&self, tile_size: V) -> (i32, i32, i32, i32)
+    where
+        V: Into<Vec2>,
+    {
+        let tile_size: Vec2 = tile_size.into();
+        let (min, max) = self.world_aabb();
+
+        // A view edge exactly on a tile edge doesn't pull in the next tile
+        let first = |value: f64, size: f64| (value / size).floor() as i32;
+        let last = |min: i32, value: f64, size: f64| ((value / size).ceil() as i32 - 1).max(min);
+
+        let min_x = first(min.x, tile_size.x);
+        let min_y = first(min.y, tile_size.y);
+        (
+            min_x,
+            min_y,
+            last(min_x, max.x, tile_size.x),
+            last(min_y, max.y, tile_size.y),
+        )
+    }
+
+    // world_view grown by margin world units on each side, a negative margin shrinks it
+    pub fn world_view_expanded(&self, margin: f64) -> Rect {
+        let view = self.world_view();
+        let topleft = view.aa_topleft();
+
+        Rect::new(
+            Point::new(topleft.x - margin, topleft.y - margin),
+            Vec2::new(view.width() + margin * 2., view.height() + margin * 2.),
+            0.,
+        )
+    }
+
+    // Screen space bounding box of the rect, it over-covers when the camera is rotated
+    pub fn world_rect_to_screen(&self, rect: Rect) -> Rect {
+        bounding_rect(&self.world_to_screen_coords_many(&rect_corners(&rect)))
+    }
+
+    // World space bounding box of the rect, it over-covers when the camera is rotated
+    pub fn screen_rect_to_world(&self, rect: Rect) -> Rect {
+        bounding_rect(&self.screen_to_world_coords_many(&rect_corners(&rect)))
+    }
+
+    // World units visible along the screen axes. Under rotation this is the extent before
+    // rotating, not the size of world_view which grows to cover the rotated view
+    pub fn visible_world_size(&self) -> Vec2 {
+        let logical_size = self.logical_size();
+
+        Vec2::new(
+            logical_size.x / self.scale.x.abs(),
+            logical_size.y / self.scale.y.abs(),
+        )
+    }
+
+    // Tight bound of the visible area, a rect of the screen size in world units rotated around
+    // its center opposite to the camera. Exact as long as the zoom is uniform
+    pub fn world_view_oriented(&self) -> Rect {
+        let size = self.visible_world_size();
+        let center = self.view_center();
+
+        Rect::new(
+            Point::new(center.x - size.x * 0.5, center.y - size.y * 0.5),
+            size,
+            -self.rotation,
+        )
+    }
+
+    // World coordinates of the screen corners, in order top-left, top-right,
+    // bottom-right, bottom-left
+    pub fn world_frustum_corners(&self) -> [Point; 4] {
+        rect_corners(&self.letterbox_viewport()).map(|corner| self.screen_to_world_coords(corner))
+    }
+
+    // Separating axis test between the rect and the (possibly rotated) view,
+    // touching edges count as visible so nothing partly on screen gets culled
+    pub fn is_rect_visible(&self, world_rect: Rect) -> bool {
+        let rect_corners = rect_corners(&world_rect);
+        let view_corners = self.world_frustum_corners();
+
+        let edge_normal = |a: Point, b: Point| Point::new(a.y - b.y, b.x - a.x);
+        let axes = [
+            Point::new(1., 0.),
+            Point::new(0., 1.),
+            edge_normal(view_corners[0], view_corners[1]),
+            edge_normal(view_corners[1], view_corners[2]),
+        ];
+
+        let project = |corners: &[Point; 4], axis: Point| {
+            corners
+                .iter()
+                .map(|corner| corner.x * axis.x + corner.y * axis.y)
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), d| {
+                    (min.min(d), max.max(d))
+                })
+        };
+
+        axes.iter().all(|axis| {
+            let (rect_min, rect_max) = project(&rect_corners, *axis);
+            let (view_min, view_max) = project(&view_corners, *axis);
+
+            rect_min <= view_max && view_min <= rect_max
+        })
+    }
+
+    // Only compares the view fields (offset, rotation, scale, position and screen_size)
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        let close = |a: f64, b: f64| (a - b).abs() <= epsilon;
+
+        close(self.offset.x, other.offset.x)
+            && close(self.offset.y, other.offset.y)
+            && close(self.rotation, other.rotation)
+            && close(self.scale.x, other.scale.x)
+            && close(self.scale.y, other.scale.y)
+            && close(self.position.x, other.position.x)
+            && close(self.position.y, other.position.y)
+            && close(self.screen_size.x, other.screen_size.x)
+            && close(self.screen_size.y, other.screen_size.y)
+    }
+
+    // Points lying exactly on the screen edges count as visible
+    pub fn is_point_visible<P>(&self, world_point: P) -> bool
+    where
+        P: Into<Point>,
+    {
+        self.is_point_visible_padded(world_point, 0.)
+    }
+
+    // Same as is_point_visible with the screen bounds grown by `margin` pixels on each side
+    pub fn is_point_visible_padded<P>(&self, world_point: P, margin: f64) -> bool
+    where
+        P: Into<Point>,
+    {
+        let screen_point = self.world_to_screen_coords(world_point);
+        let viewport = self.letterbox_viewport();
+        let topleft = viewport.aa_topleft();
+
+        (topleft.x - margin..=topleft.x + viewport.width() + margin).contains(&screen_point.x)
+            && (topleft.y - margin..=topleft.y + viewport.height() + margin)
+                .contains(&screen_point.y)
+    }
+
+    // The radius is scaled by the larger axis zoom so non uniform zoom stays conservative,
+    // a circle touching the screen edge counts as visible
+    pub fn is_circle_visible<P>(&self, world_center: P, world_radius: f64) -> bool
+    where
+        P: Into<Point>,
+    {
+        let center = self.world_to_screen_coords(world_center);
+        let scale = self.world_to_screen_scale();
+        let radius = world_radius * scale.x.max(scale.y);
+
+        let viewport = self.letterbox_viewport();
+        let topleft = viewport.aa_topleft();
+        let closest = Point::new(
+            center.x.clamp(topleft.x, topleft.x + viewport.width()),
+            center.y.clamp(topleft.y, topleft.y + viewport.height()),
+        );
+
+        (center.x - closest.x).powi(2) + (center.y - closest.y).powi(2) <= radius * radius
+    }
+
+    // Items whose position is visible, see is_point_visible, in their original order
+    pub fn cull<'a, T, F>(&self, items: &'a [T], position_of: F) -> Vec<&'a T>
+    where
+        F: Fn(&T) -> Point,
+    {
+        items
+            .iter()
+            .filter(|item| self.is_point_visible(position_of(item)))
+            .collect()
+    }
+
+    // Same as cull but gives the indices, so the slice isn't kept borrowed
+    pub fn cull_indices<T, F>(&self, items: &[T], position_of: F) -> Vec<usize>
+    where
+        F: Fn(&T) -> Point,
+    {
+        items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| self.is_point_visible(position_of(item)))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    pub fn get_position(&self) -> Point {
+        self.position
+    }
+
+    pub fn get_offset(&self) -> Point {
+        self.offset
+    }
+
+    pub fn get_rotation(&self) -> f64 {
+        self.rotation
+    }
+
+    // How much a world-up arrow is rotated on screen, including the shake. Positive is
+    // clockwise as the screen y axis points down. The YUp mirror turns it the other way,
+    // a y flip isn't taken into account
+    pub fn view_rotation(&self) -> f64 {
+        (self.rotation + self.shake.rotation().unwrap_or(0.)) * self.orientation.screen_up_sign()
+    }
+
+    // Unit world vectors pointing to the right and to the top of the screen, from the rotation,
+    // the sign of the scale and the orientation, the shake and non uniform zooms are left out
+    pub fn basis(&self) -> (Vec2, Vec2) {
+        let (sinr, cosr) = self.rotation.sin_cos();
+        let sign_x = self.scale.x.signum();
+        let sign_y = self.scale.y.signum();
+        // YUp mirrors the screen y axis, screen up then comes from world +y
+        let up = self.orientation.screen_up_sign();
+
+        (
+            Vec2::new(cosr * sign_x, -sinr * sign_y),
+            Vec2::new(-sinr * sign_x * up, -cosr * sign_y * up),
+        )
+    }
+
+    // view_rotation wrapped to -PI..PI
+    pub fn view_rotation_normalized(&self) -> f64 {
+        wrap_angle(self.view_rotation())
+    }
+
+    // Rotation to give a world object so it stays upright on screen, in -PI..PI.
+    // Its rate of change is the opposite of the camera's angular velocity
+    pub fn counter_rotation(&self) -> f64 {
+        // The mirror flips the object's rotation too, it's undone by the same world angle
+        wrap_angle(-self.view_rotation() * self.orientation.screen_up_sign())
+    }
+
+    pub fn set_position<P>(&mut self, point: P)
+    where
+        P: Into<Point>,
+    {
+        let point: Point = point.into();
+        self.position.x = point.x;
+        self.position.y = point.y;
+        self.apply_position_limits();
+        self.refresh_cache();
+    }
+
+    pub fn view_center(&self) -> Point {
+        self.screen_to_world_coords(self.screen_center())
+    }
+
+    // Unlike set_position, which puts the point at the offset, this puts it at the screen center
+    pub fn center_on<P>(&mut self, world_point: P)
+    where
+        P: Into<Point>,
+    {
+        self.apply_center_on(world_point.into());
+        self.refresh_cache();
+    }
+
+    fn apply_center_on(&mut self, world_point: Point) {
+        let current_center = self.view_center();
+
+        self.position.x += world_point.x - current_center.x;
+        self.position.y += world_point.y - current_center.y;
+        self.invalidate_cache();
+        self.apply_position_limits();
+    }
+
+    // Uniform zoom so the rect fits the screen with `padding` pixels on each side,
+    // a zero sized axis is ignored and a zero area rect only gets centered
+    pub fn fit_rect(&mut self, rect: Rect, padding: f64) {
+        self.apply_fit_rect(rect, padding);
+        self.refresh_cache();
+    }
+
+    fn apply_fit_rect(&mut self, rect: Rect, padding: f64) {
+        let size = self.logical_size();
+        let available = Vec2::new(
+            (size.x - padding * 2.).max(0.),
+            (size.y - padding * 2.).max(0.),
+        );
+
+        let fit = [(rect.width(), available.x), (rect.height(), available.y)]
+            .into_iter()
+            .filter(|(world, _)| *world > 0.)
+            .map(|(world, screen)| screen / world)
+            .reduce(f64::min);
+
+        if let Some(scale) = fit.filter(|scale| *scale > 0.) {
+            self.apply_zoom(Vec2::new(
+                scale.copysign(self.scale.x),
+                scale.copysign(self.scale.y),
+            ));
+        }
+
+        self.apply_center_on(rect.center());
+    }
+
+    // fit_rect as a transition, `t` works as in smooth_zoom_to. The position and scale move
+    // from the ones of `start`, usually a copy of the camera when it began, and match
+    // fit_rect once t reaches 1
+    pub fn smooth_fit_rect<E>(
+        &mut self,
+        start: &Camera,
+        rect: Rect,
+        padding: f64,
+        t: f64,
+        easing: E,
+    ) where
+        E: EasingFn,
+    {
+        let (start_position, start_scale) = (start.position, start.scale);
+
+        let mut target = *self;
+        target.apply_fit_rect(rect, padding);
+
+        let (position, scale) = if t >= 1. {
+            (target.position, target.scale)
+        } else {
+            let eased = easing.ease(t.clamp(0., 1.));
+            let mix = |from: f64, to: f64| from + (to - from) * eased;
+            (
+                Point::new(
+                    mix(start_position.x, target.position.x),
+                    mix(start_position.y, target.position.y),
+                ),
+                Vec2::new(
+                    mix(start_scale.x, target.scale.x),
+                    mix(start_scale.y, target.scale.y),
+                ),
+            )
+        };
+
+        // Through set_zoom and the clamps, an overshooting curve could go past the limits.
+        // fit_rect keeps the sign of the scale, the blend doesn't get to cross zero either
+        self.apply_zoom(Vec2::new(
+            same_sign(scale.x, target.scale.x),
+            same_sign(scale.y, target.scale.y),
+        ));
+        self.position = position;
+        self.invalidate_cache();
+        self.clamp_position();
+        self.refresh_cache();
+    }
+
+    // The point is multiplied by the current scale before being stored, so get_offset gives
+    // it back scaled, see set_offset_raw to store it as is and offset_by to nudge it
+    pub fn set_offset<P>(&mut self, point: P)
+    where
+        P: Into<Point>,
+    {
+        let point: Point = point.into();
+        self.offset.x = point.x * self.scale.x;
+        self.offset.y = point.y * self.scale.y;
+        self.refresh_cache();
+    }
+
+    // Screen offset summed with `offset` and the other channels, so independent systems
+    // (a lean, a recoil...) don't overwrite each other. There are at most OFFSET_CHANNELS
+    // of them, returns false when the name is new and all the channels are taken.
+    // The names are &'static str for Camera to stay Copy, and the channels are left out
+    // by serde, a deserialized camera has none
+    pub fn set_offset_channel<P>(&mut self, name: &'static str, offset: P) -> bool
+    where
+        P: Into<Point>,
+    {
+        let offset: Point = offset.into();
+
+        let slot = match self.offset_channel_index(name) {
+            Some(index) => Some(index),
+            None => self.offset_channels.iter().position(Option::is_none),
+        };
+        let Some(index) = slot else {
+            return false;
+        };
+
+        self.offset_channels[index] = Some((name, offset));
+        self.refresh_cache();
+        true
+    }
+
+    pub fn offset_channel(&self, name: &str) -> Option<Point> {
+        self.offset_channel_index(name)
+            .and_then(|index| self.offset_channels[index])
+            .map(|(_, offset)| offset)
+    }
+
+    pub fn clear_offset_channel(&mut self, name: &str) {
+        if let Some(index) = self.offset_channel_index(name) {
+            self.offset_channels[index] = None;
+            self.refresh_cache();
+        }
+    }
+
+    fn offset_channel_index(&self, name: &str) -> Option<usize> {
+        self.offset_channels
+            .iter()
+            .position(|channel| matches!(channel, Some((channel_name, _)) if *channel_name == name))
+    }
+
+    // Stores the offset verbatim, in screen pixels, get_offset then gives back the same point
+    pub fn set_offset_raw<P>(&mut self, point: P)
+    where
+        P: Into<Point>,
+    {
+        self.offset = point.into();
+        self.refresh_cache();
+    }
+
+    // Adds the delta to the stored offset as is, in screen pixels, unlike set_offset it isn't
+    // multiplied by the scale
+    pub fn offset_by<P>(&mut self, delta: P)
+    where
+        P: Into<Point>,
+    {
+        let delta: Point = delta.into();
+        self.offset.x += delta.x;
+        self.offset.y += delta.y;
+        self.refresh_cache();
+    }
+
+    pub fn move_by_world_coords<P>(&mut self, delta: P)
+    where
+        P: Into<Point>,
+    {
+        let delta: Point = delta.into();
+        self.position.x -= delta.x;
+        self.position.y -= delta.y;
+        self.invalidate_cache();
+        self.clamp_position();
+        self.refresh_cache();
+    }
+
+    pub fn move_by_screen_coords<P>(&mut self, delta: P)
+    where
+        P: Into<Point>,
+    {
+        let delta: Point = delta.into();
+        self.position.x -= delta.x / self.scale.x;
+        self.position.y -= delta.y / self.scale.y;
+        self.invalidate_cache();
+        self.clamp_position();
+        self.refresh_cache();
+    }
+
+    // Content follows the cursor, the world point grabbed under it stays under it
+    pub fn pan_drag<P>(&mut self, pixel_delta: P)
+    where
+        P: Into<Point>,
+    {
+        let pixel_delta: Point = pixel_delta.into();
+
+        let world_delta = self.screen_to_world_vector(pixel_delta);
+        self.position.x -= world_delta.x;
+        self.position.y -= world_delta.y;
+
+        self.invalidate_cache();
+        self.clamp_position();
+        self.refresh_cache();
+    }
+
+    // Camera follows the cursor, content moves the opposite way
+    pub fn pan_drag_inverted<P>(&mut self, pixel_delta: P)
+    where
+        P: Into<Point>,
+    {
+        let pixel_delta: Point = pixel_delta.into();
+        self.pan_drag((-pixel_delta.x, -pixel_delta.y));
+    }
+
+    // Framerate independent exponential smoothing, `smoothing` is the fraction of the
+    // remaining distance covered per 1/60s step
+    pub fn follow<P>(&mut self, target: P, smoothing: f64, dt: f64)
+    where
+        P: Into<Point>,
+    {
+        let target: Point = target.into();
+
+        if smoothing >= 1. {
+            self.position.x = target.x;
+            self.position.y = target.y;
+        } else {
+            let t = smoothing_step(smoothing, dt);
+            self.position.x += (target.x - self.position.x) * t;
+            self.position.y += (target.y - self.position.y) * t;
+        }
+
+        self.invalidate_cache();
+        self.clamp_position();
+        self.refresh_cache();
+    }
+
+    // follow eases into the edges instead of stopping dead on them: the target is clamped to
+    // the bounds and position limits first, the position is smoothed towards it and clamped
+    // again. A target outside the bounds is approached without overshoot or pop
+    pub fn follow_bounded<P>(&mut self, target: P, smoothing: f64, dt: f64)
+    where
+        P: Into<Point>,
+    {
+        let mut destination = *self;
+        destination.position = target.into();
+        destination.invalidate_cache();
+        destination.apply_position_limits();
+        destination.clamp_position();
+
+        self.follow(destination.position, smoothing, dt);
+    }
+
+    // Keeps the target centered while zooming out as it gets faster, both framerate independent
+    pub fn follow_dynamic<P, V>(
+        &mut self,
+        target: P,
+        target_velocity: V,
+        config: DynamicFollowConfig,
+        dt: f64,
+    ) where
+        P: Into<Point>,
+        V: Into<Vec2>,
+    {
+        let target: Point = target.into();
+        let target_velocity: Vec2 = target_velocity.into();
+
+        let zoom = self.scale.x.abs();
+        let target_zoom = config.zoom_for_speed(target_velocity.x.hypot(target_velocity.y));
+        let zoom = zoom + (target_zoom - zoom) * smoothing_step(config.zoom_smoothing, dt);
+        if zoom > 0. && self.scale.x != 0. {
+            let factor = zoom / self.scale.x.abs();
+            self.apply_zoom_at(self.view_center(), Vec2::new(factor, factor));
+        }
+
+        let center = self.view_center();
+        let t = smoothing_step(config.follow_smoothing, dt);
+        self.apply_center_on(Point::new(
+            center.x + (target.x - center.x) * t,
+            center.y + (target.y - center.y) * t,
+        ));
+        self.clamp_position();
+        self.refresh_cache();
+    }
+
+    // The deadzone is in screen pixels relative to the screen center, the camera only
+    // moves by how far the target got out of it
+    pub fn follow_with_deadzone<P>(&mut self, target: P, deadzone: Rect)
+    where
+        P: Into<Point>,
+    {
+        let center = self.screen_center();
+        let target_on_screen = self.world_to_screen_coords(target);
+        let relative = Point::new(target_on_screen.x - center.x, target_on_screen.y - center.y);
+
+        let topleft = deadzone.aa_topleft();
+        let excess = |value: f64, min: f64, max: f64| {
+            if value < min {
+                value - min
+            } else if value > max {
+                value - max
+            } else {
+                0.
+            }
+        };
+        let excess = Point::new(
+            excess(relative.x, topleft.x, topleft.x + deadzone.width()),
+            excess(relative.y, topleft.y, topleft.y + deadzone.height()),
+        );
+        if excess.x == 0. && excess.y == 0. {
+            return;
+        }
+
+        let world_delta = self.screen_to_world_vector(excess);
+        self.position.x += world_delta.x;
+        self.position.y += world_delta.y;
+
+        self.invalidate_cache();
+        self.clamp_position();
+        self.refresh_cache();
+    }
+
+    // RTS style scrolling when the cursor is within `margin` pixels of an edge of the content
+    // area, `speed` is in screen pixels per second at the very edge and ramps down to 0
+    // at the inner side of the margin, so it feels the same at every zoom
+    pub fn edge_scroll<P>(&mut self, cursor: P, margin: f64, speed: f64, dt: f64)
+    where
+        P: Into<Point>,
+    {
+        if margin <= 0. {
+            return;
+        }
+
+        let cursor: Point = cursor.into();
+        let viewport = self.letterbox_viewport();
+        let topleft = viewport.aa_topleft();
+
+        let depth = |distance: f64| ((margin - distance) / margin).clamp(0., 1.);
+        let direction = Vec2::new(
+            depth(topleft.x + viewport.width() - cursor.x) - depth(cursor.x - topleft.x),
+            depth(topleft.y + viewport.height() - cursor.y) - depth(cursor.y - topleft.y),
+        );
+        if direction.x == 0. && direction.y == 0. {
+            return;
+        }
+
+        let world_delta = self.screen_to_world_vector(direction * (speed * dt));
+        self.position.x += world_delta.x;
+        self.position.y += world_delta.y;
+
+        self.invalidate_cache();
+        self.clamp_position();
+        self.refresh_cache();
+    }
+
+    // Starts moving the position to the target, taking total_time seconds whatever the distance,
+    // drive it with update_recenter
+    pub fn recenter_over<P>(&mut self, target: P, total_time: f64)
+    where
+        P: Into<Point>,
+    {
+        self.recenter = Some(Recenter {
+            start: self.position,
+            target: target.into(),
+            duration: total_time,
+            elapsed: 0.,
+        });
+    }
+
+    // Eases out towards the recenter target, returns true once it's reached
+    // or when there is no recenter going on
+    pub fn update_recenter(&mut self, dt: f64) -> bool {
+        let Some(mut recenter) = self.recenter else {
+            return true;
+        };
+
+        recenter.elapsed += dt;
+        let progress = if recenter.duration <= 0. {
+            1.
+        } else {
+            recenter.elapsed / recenter.duration
+        };
+
+        let finished = progress >= 1.;
+        if finished {
+            self.position = recenter.target;
+            self.recenter = None;
+        } else {
+            let t = Easing::EaseOut.apply(progress);
+            self.position.x = recenter.start.x + (recenter.target.x - recenter.start.x) * t;
+            self.position.y = recenter.start.y + (recenter.target.y - recenter.start.y) * t;
+            self.recenter = Some(recenter);
+        }
+
+        self.invalidate_cache();
+        self.clamp_position();
+        self.refresh_cache();
+        finished
+    }
+
+    // Moves the camera as little as possible so the point ends up at least `margin` pixels
+    // inside the content area, nothing happens when it already is. When the margin leaves
+    // no room on an axis the point gets centered on it
+    pub fn ensure_visible<P>(&mut self, world_point: P, margin: f64)
+    where
+        P: Into<Point>,
+    {
+        let on_screen = self.world_to_screen_coords(world_point);
+        let content = self.letterbox_viewport();
+        let topleft = content.aa_topleft();
+
+        let excess = |value: f64, min: f64, max: f64| {
+            if min > max {
+                value - (min + max) * 0.5
+            } else if value < min {
+                value - min
+            } else if value > max {
+                value - max
+            } else {
+                0.
+            }
+        };
+        let excess = Point::new(
+            excess(
+                on_screen.x,
+                topleft.x + margin,
+                topleft.x + content.width() - margin,
+            ),
+            excess(
+                on_screen.y,
+                topleft.y + margin,
+                topleft.y + content.height() - margin,
+            ),
+        );
+        if excess.x == 0. && excess.y == 0. {
+            return;
+        }
+
+        let world_delta = self.screen_to_world_vector(excess);
+        self.position.x += world_delta.x;
+        self.position.y += world_delta.y;
+
+        self.invalidate_cache();
+        self.clamp_position();
+        self.refresh_cache();
+    }
+
+    // Linear movement capped at `max_delta` world units, returns true once the target is reached.
+    // A negative max_delta doesn't move the camera
+    pub fn move_towards<P>(&mut self, target: P, max_delta: f64) -> bool
+    where
+        P: Into<Point>,
+    {
+        let target: Point = target.into();
+        let max_delta = max_delta.max(0.);
+        let dx = target.x - self.position.x;
+        let dy = target.y - self.position.y;
+        let distance = dx.hypot(dy);
+
+        let reached = distance <= max_delta;
+        if reached {
+            self.position.x = target.x;
+            self.position.y = target.y;
+        } else {
+            self.position.x += dx / distance * max_delta;
+            self.position.y += dy / distance * max_delta;
+        }
+
+        self.invalidate_cache();
+        self.clamp_position();
+        self.refresh_cache();
+        reached
+    }
+
+    pub fn set_velocity<V>(&mut self, velocity: V)
+    where
+        V: Into<Vec2>,
+    {
+        self.velocity = velocity.into();
+    }
+
+    pub fn add_velocity<V>(&mut self, velocity: V)
+    where
+        V: Into<Vec2>,
+    {
+        let velocity: Vec2 = velocity.into();
+        self.velocity.x += velocity.x;
+        self.velocity.y += velocity.y;
+    }
+
+    pub fn apply_velocity(&mut self, dt: f64) {
+        self.position.x += self.velocity.x * dt;
+        self.position.y += self.velocity.y * dt;
+
+        let falloff = (-self.damping * dt).exp();
+        self.velocity.x *= falloff;
+        self.velocity.y *= falloff;
+
+        self.invalidate_cache();
+        self.clamp_position();
+        self.refresh_cache();
+    }
+
+    // Moves the camera so the view translation lands on whole pixels, sprites then get drawn
+    // on pixel boundaries. Snapping only makes sense for axis aligned cameras, it does
+    // nothing when rotated
+    pub fn snap_to_pixel(&mut self) {
+        if !is_axis_aligned(self.rotation) {
+            return;
+        }
+
+        // Rounded in physical pixels, then brought back to logical ones
+        let translation = self.to_matrix().w_axis;
+        let delta = Point::new(
+            (translation.x.round() as f64 - translation.x as f64) / self.dpi_scale,
+            (translation.y.round() as f64 - translation.y as f64) / self.dpi_scale,
+        );
+
+        let world_delta = self.screen_to_world_vector(delta);
+        self.position.x -= world_delta.x;
+        self.position.y -= world_delta.y;
+        self.invalidate_cache();
+        self.apply_position_limits();
+        self.refresh_cache();
+    }
+
+    pub fn set_pixel_snap(&mut self, pixel_snap: bool) {
+        self.pixel_snap = pixel_snap;
+        self.refresh_cache();
+    }
+
+    // Screen coordinates stay y-down window pixels, only the world is flipped
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+        self.refresh_cache();
+    }
+
+    /// See the stabilize field, a tiny positional error for no sub pixel shimmer
+    pub fn set_stabilize(&mut self, stabilize: bool) {
+        self.stabilize = stabilize;
+        self.refresh_cache();
+    }
+
+    /// Fraction of a physical pixel the translation is quantized to, 0.125 by default
+    pub fn set_stabilize_step(&mut self, step: f64) {
+        self.stabilize_step = step;
+        self.refresh_cache();
+    }
+
+    pub fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = Some(bounds);
+        self.clamp_position();
+        self.refresh_cache();
+    }
+
+    pub fn clear_bounds(&mut self) {
+        self.bounds = None;
+    }
+
+    // Moves the camera so world_view stays inside the bounds,
+    // on an axis where the view is bigger than the bounds it gets centered instead.
+    // The view is measured without the shake so shaking against an edge doesn't move the position
+    pub fn clamp_to_bounds(&mut self) {
+        self.apply_bounds();
+        self.refresh_cache();
+    }
+
+    fn apply_bounds(&mut self) {
+        let Some(bounds) = self.bounds else {
+            return;
+        };
+        let mut steady = *self;
+        steady.shake.trauma = 0.;
+        steady.invalidate_cache();
+        let view = steady.world_view();
+
+        let axis_delta = |view_min: f64, view_size: f64, bounds_min: f64, bounds_size: f64| {
+            if view_size >= bounds_size {
+                (bounds_min + bounds_size * 0.5) - (view_min + view_size * 0.5)
+            } else if view_min < bounds_min {
+                bounds_min - view_min
+            } else if view_min + view_size > bounds_min + bounds_size {
+                (bounds_min + bounds_size) - (view_min + view_size)
+            } else {
+                0.
+            }
+        };
+
+        let view_topleft = view.aa_topleft();
+        let bounds_topleft = bounds.aa_topleft();
+        self.position.x += axis_delta(
+            view_topleft.x,
+            view.width(),
+            bounds_topleft.x,
+            bounds.width(),
+        );
+        self.position.y += axis_delta(
+            view_topleft.y,
+            view.height(),
+            bounds_topleft.y,
+            bounds.height(),
+        );
+        self.invalidate_cache();
+    }
+
+    // The bounds then the position limits, like all the moves do
+    fn clamp_position(&mut self) {
+        self.apply_bounds();
+        self.apply_position_limits();
+    }
+
+    // Zooms in around the screen center as much as needed for the unrotated view to fit in the
+    // bounds. A uniform zoom stays uniform and is limited by the tighter axis, otherwise each
+    // axis is limited on its own
+    pub fn clamp_zoom_to_bounds(&mut self) {
+        let Some(bounds) = self.bounds else {
+            return;
+        };
+        if bounds.width() <= 0. || bounds.height() <= 0. {
+            return;
+        }
+
+        let logical_size = self.logical_size();
+        let mut min_scale = Vec2::new(
+            logical_size.x / bounds.width(),
+            logical_size.y / bounds.height(),
+        );
+        let uniform = (self.scale.x.abs() - self.scale.y.abs()).abs() <= 1e-9;
+        if uniform {
+            let tighter = min_scale.x.max(min_scale.y);
+            min_scale = Vec2::new(tighter, tighter);
+        }
+
+        let factor = Vec2::new(
+            (min_scale.x / self.scale.x.abs()).max(1.),
+            (min_scale.y / self.scale.y.abs()).max(1.),
+        );
+        if factor.x > 1. || factor.y > 1. {
+            self.apply_zoom_at(self.view_center(), factor);
+        }
+
+        self.clamp_position();
+        self.refresh_cache();
+    }
+
+    pub fn set_position_limits<P>(&mut self, min: P, max: P)
+    where
+        P: Into<Point>,
+    {
+        self.position_limits = Some((min.into(), max.into()));
+        self.apply_position_limits();
+        self.refresh_cache();
+    }
+
+    pub fn clear_position_limits(&mut self) {
+        self.position_limits = None;
+    }
+
+    // Cheaper than clamp_to_bounds, only the position itself is kept in the limits
+    pub fn clamp_to_position_limits(&mut self) {
+        self.apply_position_limits();
+        self.refresh_cache();
+    }
+
+    fn apply_position_limits(&mut self) {
+        let Some((min, max)) = self.position_limits else {
+            return;
+        };
+
+        self.position.x = clamp_lenient(self.position.x, min.x, max.x);
+        self.position.y = clamp_lenient(self.position.y, min.y, max.y);
+        self.invalidate_cache();
+    }
+
+    // The zoom as set, without the letterbox fit and the dpi scale, see effective_scale
+    pub fn get_zoom(&self) -> Vec2 {
+        self.scale
+    }
+
+    // Physical pixels per world unit used by to_matrix, the zoom multiplied by the letterbox
+    // fit and the dpi scale. Signed like the zoom
+    pub fn effective_scale(&self) -> Vec2 {
+        let (fit, _) = self.letterbox_fit();
+        let factor = fit * self.dpi_scale;
+
+        Vec2::new(self.scale.x * factor, self.scale.y * factor)
+    }
+
+    // Some only when both axes share the same scale
+    pub fn uniform_zoom(&self) -> Option<f64> {
+        ((self.scale.x - self.scale.y).abs() <= 1e-9).then_some(self.scale.x)
+    }
+
+    pub fn uniform_zoom_or(&self, default: f64) -> f64 {
+        self.uniform_zoom().unwrap_or(default)
+    }
+
+    pub fn set_zoom<V>(&mut self, scale: V)
+    where
+        V: Into<Vec2>,
+    {
+        self.apply_zoom(scale.into());
+        self.refresh_cache();
+    }
+
+    fn apply_zoom(&mut self, scale: Vec2) {
+        self.scale = self.clamp_scale(scale);
+        self.invalidate_cache();
+    }
+
+    // Uniform scale making `world_width` span the screen width,
+    // the current horizontal scale is returned for a width that isn't positive
+    pub fn zoom_to_fit_width(&self, world_width: f64) -> f64 {
+        if world_width <= 0. {
+            return self.scale.x;
+        }
+
+        self.logical_size().x / world_width
+    }
+
+    // Uniform scale making `world_height` span the screen height,
+    // the current vertical scale is returned for a height that isn't positive
+    pub fn zoom_to_fit_height(&self, world_height: f64) -> f64 {
+        if world_height <= 0. {
+            return self.scale.y;
+        }
+
+        self.logical_size().y / world_height
+    }
+
+    pub fn set_zoom_uniform(&mut self, scale: f64) {
+        self.set_zoom((scale, scale));
+    }
+
+    pub fn set_zoom_limits(&mut self, min: Option<Vec2>, max: Option<Vec2>) {
+        self.min_scale = min;
+        self.max_scale = max;
+        self.scale = self.clamp_scale(self.scale);
+        self.refresh_cache();
+    }
+
+    // The limits apply to the magnitude so a flipped axis stays flipped
+    fn clamp_scale(&self, scale: Vec2) -> Vec2 {
+        let mut magnitude = Vec2::new(scale.x.abs().max(MIN_SCALE), scale.y.abs().max(MIN_SCALE));
+        if let Some(min) = self.min_scale {
+            magnitude.x = magnitude.x.max(min.x);
+            magnitude.y = magnitude.y.max(min.y);
+        }
+        if let Some(max) = self.max_scale {
+            magnitude.x = magnitude.x.min(max.x);
+            magnitude.y = magnitude.y.min(max.y);
+        }
+
+        Vec2::new(magnitude.x.copysign(scale.x), magnitude.y.copysign(scale.y))
+    }
+
+    // Flips the y axis around the view center, for y-up worlds
+    pub fn set_y_flip(&mut self, flipped: bool) {
+        if (self.scale.y < 0.) == flipped {
+            return;
+        }
+
+        let center = self.view_center();
+        self.scale.y = -self.scale.y;
+        self.invalidate_cache();
+        self.center_on(center);
+    }
+
+    // Zooms around the camera origin, see ZoomAnchor::Origin
+    pub fn zoom<V>(&mut self, factor: V)
+    where
+        V: Into<Vec2>,
+    {
+        self.zoom_with_anchor(factor, ZoomAnchor::Origin);
+    }
+
+    // Multiplies the zoom by the factor while keeping the anchor in place on screen
+    pub fn zoom_with_anchor<V>(&mut self, factor: V, anchor: ZoomAnchor)
+    where
+        V: Into<Vec2>,
+    {
+        let factor: Vec2 = factor.into();
+
+        match anchor {
+            ZoomAnchor::Center => self.zoom_at_world_coords(self.view_center(), factor),
+            ZoomAnchor::ScreenPoint(point) => {
+                let world_point = self.screen_to_world_coords(point);
+                self.zoom_at_world_coords(world_point, factor);
+            }
+            ZoomAnchor::WorldPoint(point) => self.zoom_at_world_coords(point, factor),
+            ZoomAnchor::Origin => {
+                self.set_zoom(Vec2::new(self.scale.x * factor.x, self.scale.y * factor.y))
+            }
+        }
+    }
+
+    pub fn zoom_uniform(&mut self, factor: f64) {
+        self.zoom((factor, factor));
+    }
+
+    // zoom_center without the Context, around the center of the content area
+    pub fn zoom_center_uniform(&mut self, factor: f64) {
+        self.zoom_with_anchor((factor, factor), ZoomAnchor::Center);
+    }
+
+    // The position is adjusted with the factor that actually got applied after clamping,
+    // so the anchored world point stays where it was even when a zoom limit is hit
+    pub fn zoom_at_screen_coords<P, V>(&mut self, point: P, factor: V)
+    where
+        P: Into<Point>,
+        V: Into<Vec2>,
+    {
+        self.zoom_with_anchor(factor, ZoomAnchor::ScreenPoint(point.into()));
+    }
+
+    // The world point stays at the same place on screen, wherever that is
+    pub fn zoom_at_world_coords<P, V>(&mut self, world_point: P, factor: V)
+    where
+        P: Into<Point>,
+        V: Into<Vec2>,
+    {
+        self.apply_zoom_at(world_point.into(), factor.into());
+        self.refresh_cache();
+    }
+
+    fn apply_zoom_at(&mut self, world_center: Point, factor: Vec2) {
+        let old_scale = self.scale;
+        self.apply_zoom(Vec2::new(old_scale.x * factor.x, old_scale.y * factor.y));
+        let applied = Vec2::new(self.scale.x / old_scale.x, self.scale.y / old_scale.y);
+
+        self.position.x = world_center.x - (world_center.x - self.position.x) / applied.x;
+        self.position.y = world_center.y - (world_center.y - self.position.y) / applied.y;
+        self.apply_position_limits();
+    }
+
+    // Each step multiplies the zoom by `step_factor`, negative steps zoom out
+    pub fn zoom_steps_at_screen_coords<P>(&mut self, point: P, steps: i32, step_factor: f64)
+    where
+        P: Into<Point>,
+    {
+        let factor = step_factor.powi(steps);
+        self.zoom_at_screen_coords(point, (factor, factor));
+    }
+
+    // `t` is the progress of the transition in 0..1 from start_scale, usually the scale when it
+    // began, to target_scale which is reached once `t` is 1. The screen center stays put.
+    // Takes an Easing or any curve, see EasingFn
+    pub fn smooth_zoom_to<V, E>(&mut self, start_scale: V, target_scale: V, t: f64, easing: E)
+    where
+        V: Into<Vec2>,
+        E: EasingFn,
+    {
+        let start: Vec2 = start_scale.into();
+        let target: Vec2 = target_scale.into();
+
+        // An overshooting curve doesn't get to push the scale through zero
+        let eased = easing.ease(t.clamp(0., 1.));
+        let mix = |from: f64, to: f64| same_sign(from + (to - from) * eased, to);
+        let scale = Vec2::new(mix(start.x, target.x), mix(start.y, target.y));
+
+        self.zoom_at_screen_coords(
+            self.screen_center(),
+            (scale.x / self.scale.x, scale.y / self.scale.y),
+        );
+    }
+
+    pub fn add_shake(&mut self, trauma: f64) {
+        self.shake.add_trauma(trauma);
+        self.refresh_cache();
+    }
+
+    // Takes a ShakePreset or a custom ShakeConfig
+    pub fn set_shake_config<C>(&mut self, config: C)
+    where
+        C: Into<ShakeConfig>,
+    {
+        self.shake.config = config.into();
+        self.refresh_cache();
+    }
+
+    pub fn update_shake(&mut self, dt: f64) {
+        self.shake.update(dt);
+        self.refresh_cache();
+    }
+
+    // The shake noise only depends on the seed and the trauma and dt sequence,
+    // so the same seed replays the exact same shake
+    pub fn set_shake_seed(&mut self, seed: u64) {
+        self.shake.seed = seed;
+        self.refresh_cache();
+    }
+
+    // Accumulates without wrapping so continuous spins keep going, see normalize_rotation
+    pub fn rotate(&mut self, angle: f64) {
+        self.set_rotation(self.rotation + angle);
+    }
+
+    pub fn set_rotation(&mut self, angle: f64) {
+        self.apply_rotation(angle);
+        self.refresh_cache();
+    }
+
+    fn apply_rotation(&mut self, angle: f64) {
+        self.rotation = match self.rotation_limits {
+            Some((min, max)) => clamp_lenient(angle, min, max),
+            None => angle,
+        };
+        self.invalidate_cache();
+    }
+
+    pub fn set_rotation_limits(&mut self, limits: Option<(f64, f64)>) {
+        self.rotation_limits = limits;
+        self.set_rotation(self.rotation);
+    }
+
+    pub fn set_rotation_wrapped(&mut self, angle: f64) {
+        self.set_rotation(wrap_angle(angle));
+    }
+
+    // Wraps the rotation into -PI..PI
+    pub fn normalize_rotation(&mut self) {
+        self.set_rotation(wrap_angle(self.rotation));
+    }
+
+    // Spins the view in place, the world point at the screen center stays there
+    pub fn rotate_around_center(&mut self, angle: f64) {
+        self.rotate_around_screen_coords(self.screen_center(), angle);
+    }
+
+    // Turns the view around its center, the shortest way, until the world direction points
+    // to the top of the screen. A zero direction leaves the rotation as is
+    pub fn look_direction<V>(&mut self, direction: V)
+    where
+        V: Into<Vec2>,
+    {
+        let direction: Vec2 = direction.into();
+        if direction.x == 0. && direction.y == 0. {
+            return;
+        }
+
+        // Direction once scaled, so flips and non uniform zooms are taken into account. Before
+        // the YUp mirror the top of the screen is at -PI / 2, after it it's at PI / 2
+        let scaled = Vec2::new(direction.x * self.scale.x, direction.y * self.scale.y);
+        let up = self.orientation.screen_up_sign();
+        let target = -PI * 0.5 * up - scaled.y.atan2(scaled.x);
+
+        self.rotate_around_center(wrap_angle(target - self.rotation));
+    }
+
+    // Points the view from its center towards the world point
+    pub fn look_at<P>(&mut self, world_point: P)
+    where
+        P: Into<Point>,
+    {
+        let world_point: Point = world_point.into();
+        let center = self.view_center();
+
+        self.look_direction((world_point.x - center.x, world_point.y - center.y));
+    }
+
+    // Rotates while keeping the world point under the given screen point in place
+    pub fn rotate_around_screen_coords<P>(&mut self, point: P, angle: f64)
+    where
+        P: Into<Point>,
+    {
+        let point: Point = point.into();
+        let world_anchor = self.screen_to_world_coords(point);
+
+        self.apply_rotation(self.rotation + angle);
+
+        let moved_anchor = self.screen_to_world_coords(point);
+        self.position.x += world_anchor.x - moved_anchor.x;
+        self.position.y += world_anchor.y - moved_anchor.y;
+        self.apply_position_limits();
+        self.refresh_cache();
+    }
+}
+
+#[cfg(feature = "ggez")]
+impl Camera {
+    // The offset and position can't be told apart from a matrix so the whole translation
+    // ends up in the offset with the position at zero, screen_size stays at its default.
+    // Returns None when the transform isn't a plain translation, rotation and scale
+    pub fn from_draw_param(param: &DrawParam) -> Option<Camera> {
+        let (dest, rotation, scale) = match param.transform {
+            graphics::Transform::Values {
+                dest,
+                rotation,
+                scale,
+                offset,
+            } => {
+                if offset.x != 0. || offset.y != 0. {
+                    return None;
+                }
+                (dest.into(), rotation as f64, scale.into())
+            }
+            graphics::Transform::Matrix(matrix) => {
+                let transform = Transform::from_matrix(matrix.into());
+                (transform.dest, transform.rotation, transform.scale)
+            }
+        };
+
+        let mut camera = Camera {
+            offset: dest,
+            rotation,
+            scale,
+            ..Default::default()
+        };
+        camera.refresh_cache();
+
+        if let graphics::Transform::Matrix(matrix) = param.transform {
+            if !camera.to_matrix().abs_diff_eq(matrix.into(), 1e-4) {
+                return None;
+            }
+        }
+
+        Some(camera)
+    }
+
+    /// DrawParam for an object at a world position, with no rotation or scale
+    ///
+    /// ```
+    /// use camera2d_ggez::Camera;
+    /// use ggez::graphics::Transform;
+    ///
+    /// let camera = Camera::default();
+    /// let param = camera.draw_param_at((100., 50.));
+    ///
+    /// let Transform::Matrix(matrix) = param.transform else {
+    ///     panic!("the camera transform is a matrix");
+    /// };
+    /// assert_eq!((matrix.w.x, matrix.w.y), (100., 50.));
+    /// ```
+    pub fn draw_param_at<P>(&self, dest: P) -> DrawParam
+    where
+        P: Into<Point>,
+    {
+        let object = Transform {
+            dest: dest.into(),
+            ..Default::default()
+        };
+
+        DrawParam::default().transform(self.apply_matrix(object))
+    }
+
+    // DrawParam drawing an object at the world transform through the camera
+    pub fn world_draw_param<T>(&self, transform: T) -> DrawParam
+    where
+        T: Into<Transform>,
+    {
+        DrawParam::default().transform(self.apply_matrix(transform))
+    }
+
+    // DrawParam for a texture of texture_size pixels covering world_size world units,
+    // origin is the normalized point of the sprite put on dest, (0.5, 0.5) for its center
+    pub fn world_sprite_param<P, V, T, O>(
+        &self,
+        dest: P,
+        world_size: V,
+        texture_size: T,
+        origin: O,
+    ) -> DrawParam
+    where
+        P: Into<Point>,
+        V: Into<Vec2>,
+        T: Into<Vec2>,
+        O: Into<Point>,
+    {
+        let world_size: Vec2 = world_size.into();
+        let texture_size: Vec2 = texture_size.into();
+        let origin: Point = origin.into();
+
+        self.world_draw_param(Transform {
+            dest: dest.into(),
+            scale: Vec2::new(world_size.x / texture_size.x, world_size.y / texture_size.y),
+            // Transform offsets are in scaled units, so world units here
+            offset: Point::new(origin.x * world_size.x, origin.y * world_size.y),
+            ..Default::default()
+        })
+    }
+
+    // Meshes, images, text and anything else Drawable, positioned by the world transform
+    pub fn draw_world<D, T>(&self, canvas: &mut Canvas, drawable: &D, transform: T)
+    where
+        D: Drawable,
+        T: Into<Transform>,
+    {
+        canvas.draw(drawable, self.world_draw_param(transform));
+    }
+
+    /// Uses screen_size for anything relying on the screen dimensions,
+    /// so keep it in sync with the actual window size.
+    /// ggez reports the mouse in physical pixels, it's brought back to logical ones
+    ///
+    /// ```no_run
+    /// # use camera2d_ggez::Camera;
+    /// # fn update(ctx: &ggez::Context, camera: &mut Camera) {
+    /// let (width, height) = ctx.gfx.drawable_size();
+    /// camera.set_screen_size((
+    ///     width as f64 / camera.dpi_scale,
+    ///     height as f64 / camera.dpi_scale,
+    /// ));
+    ///
+    /// let mouse = camera.mouse_world_position(ctx);
+    /// println!("the mouse is at {mouse:?} in the world");
+    /// # }
+    /// ```
+    pub fn mouse_world_position(&self, ctx: &Context) -> Point {
+        let mouse: Point = ctx.mouse.position().into();
+
+        self.screen_to_world_coords(mouse * (1. / self.dpi_scale))
+    }
+
+    // Zooms around the center of the content area like ZoomAnchor::Center, so the viewport
+    // and design resolution are taken into account. The Context isn't needed anymore
+    pub fn zoom_center<V>(&mut self, _ctx: &Context, factor: V)
+    where
+        V: Into<Vec2>,
+    {
+        self.zoom_with_anchor(factor, ZoomAnchor::Center);
+    }
+
+    // Dev aid drawing the view outline, a crosshair on the view center and a dot on the
+    // offset point, all in a single mesh drawn through the camera
+    pub fn debug_draw(&self, ctx: &mut Context, canvas: &mut Canvas, color: Color) -> GameResult {
+        let mesh = Mesh::from_data(ctx, self.debug_mesh(color)?.build());
+        canvas.draw(&mesh, *self);
+
+        Ok(())
+    }
+
+    fn debug_mesh(&self, color: Color) -> GameResult<MeshBuilder> {
+        let to_mint = |point: Point| mint::Point2 {
+            x: point.x as f32,
+            y: point.y as f32,
+        };
+        let pixel = self.screen_to_world_scale();
+        let pixel = pixel.x.max(pixel.y) as f32;
+
+        let center = self.view_center();
+        let arm = pixel as f64 * 10.;
+        let corners = self.world_frustum_corners().map(to_mint);
+
+        let mut builder = MeshBuilder::new();
+        builder
+            .polygon(DrawMode::stroke(pixel * 2.), &corners, color)?
+            .line(
+                &[
+                    to_mint(Point::new(center.x - arm, center.y)),
+                    to_mint(Point::new(center.x + arm, center.y)),
+                ],
+                pixel * 2.,
+                color,
+            )?
+            .line(
+                &[
+                    to_mint(Point::new(center.x, center.y - arm)),
+                    to_mint(Point::new(center.x, center.y + arm)),
+                ],
+                pixel * 2.,
+                color,
+            )?
+            .circle(
+                DrawMode::fill(),
+                to_mint(self.position),
+                pixel * 4.,
+                pixel * 0.5,
+                color,
+            )?;
+
+        Ok(builder)
+    }
+}
+
+// Corners of the axis aligned rect, in order top-left, top-right, bottom-right, bottom-left
+fn rect_corners(rect: &Rect) -> [Point; 4] {
+    let topleft = rect.aa_topleft();
+    let botright = Point::new(topleft.x + rect.width(), topleft.y + rect.height());
+
+    [
+        topleft,
+        Point::new(botright.x, topleft.y),
+        botright,
+        Point::new(topleft.x, botright.y),
+    ]
+}
+
+fn bounding_rect(points: &[Point]) -> Rect {
+    let (min, max) = bounding_box(points);
+
+    Rect::new(min, Vec2::new(max.x - min.x, max.y - min.y), 0.)
+}
+
+// (min, max) over all the points, each bound can come from a different point
+fn bounding_box(points: &[Point]) -> (Point, Point) {
+    let mut min = points[0];
+    let mut max = points[0];
+    for point in &points[1..] {
+        min.x = min.x.min(point.x);
+        min.y = min.y.min(point.y);
+        max.x = max.x.max(point.x);
+        max.y = max.y.max(point.y);
+    }
+
+    (min, max)
+}
+
+// Rotated by a multiple of 90 degrees
+fn is_axis_aligned(rotation: f64) -> bool {
+    let (sinr, cosr) = rotation.sin_cos();
+    (sinr * cosr).abs() < 1e-9
+}
+
+// Fraction of the remaining distance to cover this frame, `smoothing` being the fraction
+// covered per 1/60s step
+fn smoothing_step(smoothing: f64, dt: f64) -> f64 {
+    if smoothing >= 1. {
+        return 1.;
+    }
+
+    1. - (1. - smoothing.max(0.)).powf(dt * 60.)
+}
+
+// max then min rather than f64::clamp, which panics on inverted limits, max wins then
+fn clamp_lenient(value: f64, min: f64, max: f64) -> f64 {
+    value.max(min).min(max)
+}
+
+fn wrap_angle(angle: f64) -> f64 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+// The value, or zero when its sign differs from the sign of `sign`
+fn same_sign(value: f64, sign: f64) -> f64 {
+    (value * sign.signum()).max(0.) * sign.signum()
+}
+
+#[cfg(feature = "ggez")]
+impl From<Camera> for DrawParam {
+    fn from(value: Camera) -> Self {
+        DrawParam::default().transform(value.to_matrix())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The matrices are f32, conversions are only exact to a few thousandths of a pixel
+    const EPSILON: f64 = 1e-2;
+
+    fn assert_point_eq(a: Point, b: Point) {
+        assert!(
+            (a.x - b.x).abs() <= EPSILON && (a.y - b.y).abs() <= EPSILON,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    fn assert_rect_eq(rect: Rect, topleft: Point, size: Vec2) {
+        assert_point_eq(rect.aa_topleft(), topleft);
+        assert_point_eq(
+            Point::new(rect.width(), rect.height()),
+            Point::new(size.x, size.y),
+        );
+    }
+
+    fn assert_rect_contains(outer: Rect, inner: Rect) {
+        let (outer_min, inner_min) = (outer.aa_topleft(), inner.aa_topleft());
+
+        assert!(
+            outer_min.x <= inner_min.x + EPSILON
+                && outer_min.y <= inner_min.y + EPSILON
+                && outer_min.x + outer.width() + EPSILON >= inner_min.x + inner.width()
+                && outer_min.y + outer.height() + EPSILON >= inner_min.y + inner.height(),
+            "{outer:?} doesn't contain {inner:?}"
+        );
+    }
+
+    #[test]
+    fn zooming_past_the_limit_stops_at_the_bound() {
+        let mut camera = Camera::new((0., 0.), 0.3, (1., 1.), (120., -40.), (1280., 720.));
+        camera.set_zoom_limits(Some(Vec2::new(0.5, 0.5)), Some(Vec2::new(4., 4.)));
+        let anchor = Point::new(300., 200.);
+        let world_anchor = camera.screen_to_world_coords(anchor);
+
+        for _ in 0..10 {
+            camera.zoom_at_screen_coords(anchor, (2., 2.));
+            assert_point_eq(camera.screen_to_world_coords(anchor), world_anchor);
+        }
+        assert_eq!(camera.scale, Vec2::new(4., 4.));
+
+        for _ in 0..10 {
+            camera.zoom_at_screen_coords(anchor, (0.5, 0.5));
+            assert_point_eq(camera.screen_to_world_coords(anchor), world_anchor);
+        }
+        assert_eq!(camera.scale, Vec2::new(0.5, 0.5));
+    }
+
+    #[test]
+    fn every_zoom_method_is_clamped() {
+        let mut camera = Camera::default();
+        camera.set_zoom_limits(Some(Vec2::new(0.5, 0.25)), Some(Vec2::new(2., 3.)));
+
+        camera.set_zoom((10., 10.));
+        assert_eq!(camera.scale, Vec2::new(2., 3.));
+
+        camera.zoom((0.01, 0.01));
+        assert_eq!(camera.scale, Vec2::new(0.5, 0.25));
+
+        camera.zoom_uniform(100.);
+        assert_eq!(camera.scale, Vec2::new(2., 3.));
+
+        // Setting the limits clamps the current zoom too
+        camera.set_zoom_limits(None, Some(Vec2::new(1., 1.)));
+        assert_eq!(camera.scale, Vec2::new(1., 1.));
+
+        assert_eq!(camera.with_zoom(5.).scale, Vec2::new(1., 1.));
+        assert_eq!(
+            Camera::default().with_zoom(0.).scale,
+            Vec2::new(MIN_SCALE, MIN_SCALE)
+        );
+    }
+
+    #[test]
+    fn follow_converges_without_overshoot() {
+        let mut camera = Camera::default();
+        let target = Point::new(500., -250.);
+
+        for _ in 0..600 {
+            camera.follow(target, 0.1, 1. / 60.);
+            assert!(camera.position.x <= target.x && camera.position.y >= target.y);
+        }
+        assert!((camera.position.x - target.x).abs() < 1e-9);
+        assert!((camera.position.y - target.y).abs() < 1e-9);
+    }
+
+    #[test]
+    fn follow_rate_is_framerate_independent() {
+        let target = Point::new(1000., 400.);
+
+        let mut fast = Camera::default();
+        for _ in 0..4 {
+            fast.follow(target, 0.2, 1. / 120.);
+        }
+        let mut slow = Camera::default();
+        slow.follow(target, 0.2, 1. / 30.);
+
+        assert!((fast.position.x - slow.position.x).abs() < 1e-9);
+        assert!((fast.position.y - slow.position.y).abs() < 1e-9);
+    }
+
+    #[test]
+    fn full_smoothing_snaps_to_the_target() {
+        let mut camera = Camera::default();
+        camera.follow((12.5, -3.), 1., 1. / 60.);
+
+        assert_eq!(camera.position, Point::new(12.5, -3.));
+    }
+
+    #[test]
+    fn world_view_unrotated() {
+        let camera = Camera::default();
+
+        assert_rect_eq(camera.world_view(), Point::ZERO, Vec2::new(1920., 1080.));
+    }
+
+    #[test]
+    fn world_view_zoomed() {
+        let mut camera = Camera::default();
+        camera.set_position((100., 50.));
+        camera.set_zoom((2., 2.));
+
+        assert_rect_eq(
+            camera.world_view(),
+            Point::new(100., 50.),
+            Vec2::new(960., 540.),
+        );
+    }
+
+    #[test]
+    fn world_view_rotated_45_degrees() {
+        let mut camera = Camera::default();
+        camera.set_rotation(PI / 4.);
+        let half_sqrt2 = 0.5f64.sqrt();
+
+        // The screen corners end up at (0, 0), (1920h, -1920h), (3000h, -840h) and (1080h, 1080h)
+        assert_rect_eq(
+            camera.world_view(),
+            Point::new(0., -1920. * half_sqrt2),
+            Vec2::new(3000. * half_sqrt2, 3000. * half_sqrt2),
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut camera = Camera::new((10., 20.), 0.5, (2., 3.), (-4., 8.), (800., 600.));
+        camera.set_zoom_limits(Some(Vec2::new(0.5, 0.5)), None);
+        camera.set_orientation(Orientation::YUp);
+
+        let json = serde_json::to_string(&camera).unwrap();
+        let deserialized: Camera = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, camera);
+    }
+
+    #[test]
+    fn shake_trauma_decays_to_zero() {
+        let mut camera = Camera::default();
+        camera.add_shake(0.6);
+        camera.add_shake(0.6);
+        assert_eq!(camera.shake.trauma, 1.);
+
+        // The default config loses 1 trauma per second
+        camera.update_shake(0.5);
+        assert!(camera.shake.trauma > 0.);
+        assert!(camera.shake.offset().is_some());
+
+        camera.update_shake(0.5);
+        assert_eq!(camera.shake.trauma, 0.);
+        assert!(camera.shake.offset().is_none());
+    }
+
+    #[test]
+    fn zero_trauma_matrix_is_unchanged() {
+        let steady = Camera::new((960., 540.), 0.4, (2., 2.), (30., -70.), (1920., 1080.));
+        let mut shaken = steady;
+        shaken.add_shake(1.);
+        shaken.update_shake(0.3);
+        shaken.update_shake(1.);
+
+        assert_eq!(shaken.shake.trauma, 0.);
+        assert_eq!(shaken.to_matrix(), steady.to_matrix());
+        assert_eq!(shaken.position, steady.position);
+    }
+
+    #[test]
+    fn partial_eq_is_exact_and_approx_eq_has_a_tolerance() {
+        let a = Camera::new((0., 0.), 0., (1., 1.), (10., 10.), (800., 600.));
+        let mut b = a;
+        assert_eq!(a, b);
+
+        // The cache is left out
+        b.refresh_cache();
+        assert_eq!(a, b);
+
+        b.position.x += 1e-4;
+        assert_ne!(a, b);
+        assert!(a.approx_eq(&b, 1e-3));
+        assert!(!a.approx_eq(&b, 1e-5));
+    }
+
+    #[test]
+    fn center_on_puts_the_point_at_the_screen_center() {
+        let mut camera = Camera::new((100., 50.), 0.7, (2.5, 2.5), (30., -40.), (1280., 720.));
+        let point = Point::new(123., 456.);
+        camera.center_on(point);
+
+        assert_point_eq(camera.world_to_screen_coords(point), Point::new(640., 360.));
+    }
+
+    #[test]
+    fn center_on_with_a_non_uniform_zoom() {
+        let mut camera = Camera::new((0., 0.), -2., (0.5, 3.), (0., 0.), (1920., 1080.));
+        let point = Point::new(-800., 75.);
+        camera.center_on(point);
+
+        assert_point_eq(camera.world_to_screen_coords(point), Point::new(960., 540.));
+    }
+
+    #[test]
+    fn fit_rect_wide() {
+        let mut camera = Camera::default();
+        let rect = Rect::new(Point::new(0., 0.), Vec2::new(4000., 500.), 0.);
+        camera.fit_rect(rect, 0.);
+
+        assert!((camera.scale.x - 1920. / 4000.).abs() < 1e-9);
+        assert_rect_contains(camera.world_view(), rect);
+        assert!((camera.world_view().width() - 4000.).abs() < 1.);
+        assert_point_eq(camera.view_center(), rect.center());
+    }
+
+    #[test]
+    fn fit_rect_tall() {
+        let mut camera = Camera::default();
+        let rect = Rect::new(Point::new(-100., -100.), Vec2::new(200., 1000.), 0.);
+        camera.fit_rect(rect, 0.);
+
+        assert!((camera.scale.y - 1080. / 1000.).abs() < 1e-9);
+        assert_rect_contains(camera.world_view(), rect);
+        assert!((camera.world_view().height() - 1000.).abs() < 1.);
+        assert_point_eq(camera.view_center(), rect.center());
+    }
+
+    #[test]
+    fn fit_rect_square_with_padding() {
+        let mut camera = Camera::default();
+        let rect = Rect::new(Point::new(250., 300.), Vec2::new(300., 300.), 0.);
+        camera.fit_rect(rect, 60.);
+
+        // (1080 - 2 * 60) / 300
+        assert!((camera.scale.x - 3.2).abs() < 1e-9);
+        assert_eq!(camera.scale.x, camera.scale.y);
+        assert_rect_contains(camera.world_view(), rect);
+        assert_point_eq(camera.view_center(), rect.center());
+    }
+
+    #[test]
+    fn fit_rect_zero_area_only_centers() {
+        let mut camera = Camera::default();
+        camera.set_zoom((2., 2.));
+        camera.fit_rect(Rect::new(Point::new(5., 5.), Vec2::ZERO, 0.), 0.);
+
+        assert_eq!(camera.scale, Vec2::new(2., 2.));
+        assert_point_eq(camera.view_center(), Point::new(5., 5.));
+    }
+
+    #[test]
+    fn point_visibility() {
+        let camera = Camera::default();
+
+        assert!(camera.is_point_visible((100., 100.)));
+        assert!(!camera.is_point_visible((-1., 100.)));
+        assert!(!camera.is_point_visible((100., 1081.)));
+        // On the boundary
+        assert!(camera.is_point_visible((0., 0.)));
+        assert!(camera.is_point_visible((1920., 1080.)));
+        assert!(camera.is_point_visible((1920., 500.)));
+    }
+
+    #[test]
+    fn padded_point_visibility() {
+        let camera = Camera::default();
+
+        assert!(camera.is_point_visible_padded((-5., 100.), 10.));
+        assert!(!camera.is_point_visible_padded((-5., 100.), 2.));
+        assert!(camera.is_point_visible_padded((1930., 1090.), 10.));
+    }
+
+    #[test]
+    fn point_visibility_rotated() {
+        let mut camera = Camera::default();
+        camera.rotate_around_center(PI);
+
+        // Turned around the center, (100, 100) is drawn at (1820, 980)
+        assert!(camera.is_point_visible((100., 100.)));
+        assert!(!camera.is_point_visible((-100., 500.)));
+        assert!(!camera.is_point_visible((2000., 500.)));
+    }
+
+    #[test]
+    fn rect_visibility() {
+        let camera = Camera::default();
+        let rect =
+            |x: f64, y: f64, w: f64, h: f64| Rect::new(Point::new(x, y), Vec2::new(w, h), 0.);
+
+        // Fully inside, fully outside, partly overlapping and covering the whole view
+        assert!(camera.is_rect_visible(rect(100., 100., 50., 50.)));
+        assert!(!camera.is_rect_visible(rect(3000., 3000., 10., 10.)));
+        assert!(!camera.is_rect_visible(rect(-200., 100., 100., 100.)));
+        assert!(camera.is_rect_visible(rect(-50., -50., 100., 100.)));
+        assert!(camera.is_rect_visible(rect(1900., 1000., 500., 500.)));
+        assert!(camera.is_rect_visible(rect(-1000., -1000., 5000., 5000.)));
+    }
+
+    #[test]
+    fn rect_visibility_rotated() {
+        let mut camera = Camera::default();
+        camera.rotate_around_center(PI / 4.);
+        let corners = camera.world_frustum_corners();
+        let (min, _) = camera.world_aabb();
+
+        let around = |point: Point| {
+            Rect::new(
+                Point::new(point.x - 5., point.y - 5.),
+                Vec2::new(10., 10.),
+                0.,
+            )
+        };
+        assert!(camera.is_rect_visible(around(camera.view_center())));
+        for corner in corners {
+            assert!(camera.is_rect_visible(around(corner)));
+        }
+        // In the bounding box of the view, but outside of the rotated view itself
+        assert!(!camera.is_rect_visible(Rect::new(min, Vec2::new(10., 10.), 0.)));
+    }
+
+    #[test]
+    fn position_is_clamped_against_each_edge() {
+        let bounds = Rect::new(Point::ZERO, Vec2::new(4000., 3000.), 0.);
+        // (position pushed out, position after clamping), the view is 1920x1080
+        let cases = [
+            ((-100., 500.), (0., 500.)),
+            ((3000., 500.), (2080., 500.)),
+            ((500., -50.), (500., 0.)),
+            ((500., 2500.), (500., 1920.)),
+        ];
+
+        for (pushed, clamped) in cases {
+            let mut camera = Camera::default();
+            camera.set_bounds(bounds);
+            camera.set_position(pushed);
+            camera.clamp_to_bounds();
+
+            assert_point_eq(camera.position, clamped.into());
+            assert_rect_contains(bounds, camera.world_view());
+        }
+    }
+
+    #[test]
+    fn oversized_view_is_centered_on_the_bounds() {
+        let mut camera = Camera::default();
+        camera.set_bounds(Rect::new(Point::ZERO, Vec2::new(1000., 500.), 0.));
+        camera.set_position((300., 200.));
+        camera.clamp_to_bounds();
+        assert_point_eq(camera.view_center(), Point::new(500., 250.));
+
+        // Doesn't move any further once centered
+        let centered = camera.position;
+        camera.clamp_to_bounds();
+        assert_point_eq(camera.position, centered);
+    }
+
+    #[test]
+    fn movement_respects_the_bounds() {
+        let mut camera = Camera::default();
+        camera.set_bounds(Rect::new(Point::ZERO, Vec2::new(4000., 3000.), 0.));
+
+        camera.follow((-500., -500.), 1., 1. / 60.);
+        assert_point_eq(camera.position, Point::ZERO);
+
+        camera.move_by_world_coords((-10000., 0.));
+        assert_point_eq(camera.position, Point::new(2080., 0.));
+    }
+
+    #[test]
+    fn shake_doesnt_move_the_clamped_position() {
+        let mut camera = Camera::default();
+        camera.set_bounds(Rect::new(Point::ZERO, Vec2::new(4000., 3000.), 0.));
+        camera.add_shake(1.);
+        camera.update_shake(0.1);
+        assert!(camera.shake.offset().is_some());
+
+        camera.clamp_to_bounds();
+        assert_point_eq(camera.position, Point::ZERO);
+    }
+
+    #[test]
+    fn move_towards_never_overshoots() {
+        let mut camera = Camera::default();
+        let target = Point::new(6., 8.);
+
+        // 10 units away, 3 per step
+        for step in 1..=3 {
+            assert!(!camera.move_towards(target, 3.));
+            let travelled = camera.position.x.hypot(camera.position.y);
+            assert!((travelled - 3. * step as f64).abs() < 1e-9);
+        }
+        assert!(camera.move_towards(target, 3.));
+        assert_eq!(camera.position, target);
+
+        assert!(camera.move_towards(target, 3.));
+        assert_eq!(camera.position, target);
+    }
+
+    #[test]
+    fn move_towards_with_a_negative_max_delta_stays_put() {
+        let mut camera = Camera::default();
+
+        assert!(!camera.move_towards((100., 0.), -5.));
+        assert_eq!(camera.position, Point::ZERO);
+    }
+
+    #[test]
+    fn uniform_zoom_helpers_match_the_per_axis_ones() {
+        let camera = Camera::new((960., 540.), 0.2, (1.5, 1.5), (40., 10.), (1920., 1080.));
+
+        let mut uniform = camera;
+        uniform.zoom_uniform(2.);
+        let mut per_axis = camera;
+        per_axis.zoom((2., 2.));
+        assert_eq!(uniform, per_axis);
+
+        uniform.set_zoom_uniform(0.75);
+        per_axis.set_zoom((0.75, 0.75));
+        assert_eq!(uniform, per_axis);
+
+        let window_center = Point::new(960., 540.);
+        let world_center = uniform.screen_to_world_coords(window_center);
+        uniform.zoom_center_uniform(2.);
+        per_axis.zoom_with_anchor((2., 2.), ZoomAnchor::Center);
+        assert_eq!(uniform, per_axis);
+        assert_point_eq(uniform.world_to_screen_coords(world_center), window_center);
+    }
+
+    #[test]
+    fn center_zoom_keeps_the_viewport_center() {
+        let mut camera = Camera::new((0., 0.), 0.3, (1., 1.), (40., 10.), (1920., 1080.));
+        camera.set_viewport(Rect::new(Point::new(960., 0.), Vec2::new(960., 540.), 0.));
+        let viewport_center = Point::new(1440., 270.);
+        assert_point_eq(camera.screen_center(), viewport_center);
+
+        let world_center = camera.screen_to_world_coords(viewport_center);
+        camera.zoom_center_uniform(3.);
+        assert_point_eq(camera.world_to_screen_coords(world_center), viewport_center);
+    }
+
+    #[test]
+    fn rotating_around_a_screen_point_keeps_its_world_point() {
+        let mut camera = Camera::new((960., 540.), 0.2, (1.5, 1.5), (100., 50.), (1280., 720.));
+        let point = Point::new(300., 200.);
+        let world_point = camera.screen_to_world_coords(point);
+
+        camera.rotate_around_screen_coords(point, 0.9);
+        assert!((camera.rotation - 1.1).abs() < 1e-9);
+        assert_point_eq(camera.screen_to_world_coords(point), world_point);
+
+        camera.rotate_around_screen_coords(point, -2.5);
+        assert_point_eq(camera.screen_to_world_coords(point), world_point);
+    }
+
+    #[test]
+    fn batch_conversions_match_the_single_ones() {
+        let camera = Camera::new((960., 540.), -0.8, (2., 0.5), (-30., 75.), (1920., 1080.));
+        let points: Vec<Point> = (0..20)
+            .map(|i| Point::new(i as f64 * 97. - 500., (i * i) as f64 * 3.5 - 200.))
+            .collect();
+
+        let screen = camera.world_to_screen_coords_many(&points);
+        let world = camera.screen_to_world_coords_many(&points);
+        assert_eq!(screen.len(), points.len());
+        assert_eq!(world.len(), points.len());
+
+        for (i, point) in points.iter().enumerate() {
+            assert_eq!(screen[i], camera.world_to_screen_coords(*point));
+            assert_eq!(world[i], camera.screen_to_world_coords(*point));
+        }
+    }
+
+    #[test]
+    fn design_resolution_in_a_wider_window() {
+        let mut camera = Camera::default();
+        camera.set_design_resolution((1920., 1080.));
+        camera.set_screen_size((2560., 1080.));
+
+        // Pillarboxed, 320 pixel bars on the left and the right
+        assert_rect_eq(
+            camera.letterbox_viewport(),
+            Point::new(320., 0.),
+            Vec2::new(1920., 1080.),
+        );
+        assert_point_eq(
+            camera.world_to_screen_coords((0., 0.)),
+            Point::new(320., 0.),
+        );
+        assert_point_eq(
+            camera.world_to_screen_coords((1920., 1080.)),
+            Point::new(2240., 1080.),
+        );
+    }
+
+    #[test]
+    fn design_resolution_in_a_taller_window() {
+        let mut camera = Camera::default();
+        camera.set_design_resolution((1920., 1080.));
+        camera.set_screen_size((1920., 1440.));
+
+        // Letterboxed, 180 pixel bars at the top and the bottom
+        assert_rect_eq(
+            camera.letterbox_viewport(),
+            Point::new(0., 180.),
+            Vec2::new(1920., 1080.),
+        );
+        assert_point_eq(
+            camera.world_to_screen_coords((1920., 1080.)),
+            Point::new(1920., 1260.),
+        );
+    }
+
+    #[test]
+    fn design_resolution_scales_uniformly() {
+        let mut camera = Camera::default();
+        camera.set_design_resolution((1920., 1080.));
+        camera.set_screen_size((3840., 2400.));
+
+        // Fit 2, limited by the width, with 120 pixel bars at the top and the bottom
+        assert_point_eq(
+            camera.world_to_screen_coords((10., 10.)),
+            Point::new(20., 140.),
+        );
+        let scale = camera.world_to_screen_scale();
+        assert_point_eq(Point::new(scale.x, scale.y), Point::new(2., 2.));
+    }
+
+    #[test]
+    fn parallax_depths() {
+        let camera = Camera::new((960., 540.), 0.3, (2., 2.), (400., -120.), (1920., 1080.));
+        let at = |position: Point| {
+            let mut camera = camera;
+            camera.set_position(position);
+            camera.to_matrix()
+        };
+
+        assert_eq!(camera.to_matrix_parallax(1.), camera.to_matrix());
+        assert!(camera
+            .to_matrix_parallax(0.5)
+            .abs_diff_eq(at(Point::new(200., -60.)), 1e-3));
+        // Static layer, zoom and rotation still apply
+        assert!(camera
+            .to_matrix_parallax(0.)
+            .abs_diff_eq(at(Point::ZERO), 1e-3));
+    }
+
+    #[test]
+    fn lerp_endpoints_and_midpoint() {
+        let a = Camera::new((0., 0.), 0., (1., 1.), (0., 0.), (800., 600.));
+        let b = Camera::new((10., 20.), PI / 2., (3., 3.), (100., -50.), (1600., 1200.));
+
+        assert_eq!(Camera::lerp(&a, &b, 0.), a);
+        assert_eq!(Camera::lerp(&a, &b, 1.), b);
+
+        let expected = Camera::new((5., 10.), PI / 4., (2., 2.), (50., -25.), (1200., 900.));
+        assert!(Camera::lerp(&a, &b, 0.5).approx_eq(&expected, 1e-9));
+    }
+
+    #[test]
+    fn lerp_takes_the_other_fields_from_a_until_the_end() {
+        let mut a = Camera::default();
+        a.set_bounds(Rect::new(
+            Point::new(-5000., -5000.),
+            Vec2::new(10000., 10000.),
+            0.,
+        ));
+        a.recenter_over((100., 100.), 1.);
+        let mut b = Camera::default();
+        b.set_position((50., 50.));
+        b.set_orientation(Orientation::YUp);
+        b.set_shake_seed(7);
+
+        for t in [0., 0.5] {
+            let camera = Camera::lerp(&a, &b, t);
+            assert_eq!(camera.bounds, a.bounds);
+            assert_eq!(camera.orientation, Orientation::YDown);
+            assert_eq!(camera.shake, a.shake);
+            assert_eq!(camera.recenter, None);
+        }
+
+        b.recenter_over((-100., 0.), 1.);
+        let end = Camera::lerp(&a, &b, 1.);
+        assert_eq!(end.bounds, None);
+        assert_eq!(end.orientation, Orientation::YUp);
+        assert_eq!(end.shake, b.shake);
+        assert_eq!(end.recenter, None);
+        assert_eq!(end.position, b.position);
+    }
+
+    #[test]
+    fn lerp_rotation_takes_the_short_way() {
+        let mut a = Camera::default();
+        a.set_rotation(350f64.to_radians());
+        let mut b = Camera::default();
+        b.set_rotation(10f64.to_radians());
+
+        // 350 -> 360 rather than back down to 180
+        let middle = Camera::lerp(&a, &b, 0.5);
+        assert!((middle.rotation - TAU).abs() < 1e-9);
+        let quarter = Camera::lerp(&b, &a, 0.25);
+        assert!((quarter.rotation - 5f64.to_radians()).abs() < 1e-9);
+    }
+
+    fn assert_cache_is_fresh(camera: &Camera) {
+        let fresh = camera.compute_matrix(camera.position);
+        let (inputs, matrix, inverse) = camera.cache.expect("the methods fill the cache");
+
+        assert_eq!(inputs, camera.view_inputs());
+        assert_eq!(matrix, fresh);
+        assert_eq!(inverse, fresh.inverse());
+        assert_eq!(camera.to_matrix(), fresh);
+    }
+
+    #[test]
+    fn cache_matches_a_fresh_matrix_after_each_mutation() {
+        let mut camera = Camera::builder().screen_size((1280., 720.)).build();
+        assert_cache_is_fresh(&camera);
+
+        let mutations: [fn(&mut Camera); 19] = [
+            |camera| camera.set_position((25., -40.)),
+            |camera| camera.move_by_world_coords((5., 5.)),
+            |camera| camera.move_by_screen_coords((-12., 3.)),
+            |camera| camera.center_on((100., 100.)),
+            |camera| camera.zoom((1.5, 1.5)),
+            |camera| camera.set_zoom((2., 3.)),
+            |camera| camera.zoom_at_screen_coords((200., 100.), (0.8, 0.8)),
+            |camera| camera.rotate(0.4),
+            |camera| camera.set_rotation(-1.),
+            |camera| camera.rotate_around_center(0.25),
+            |camera| camera.set_offset((30., 20.)),
+            |camera| camera.set_screen_size((1920., 1080.)),
+            |camera| camera.set_design_resolution((640., 360.)),
+            |camera| camera.set_orientation(Orientation::YUp),
+            |camera| {
+                camera.add_shake(0.5);
+                camera.update_shake(0.1);
+            },
+            |camera| assert!(camera.set_offset_channel("lean", (4., 0.))),
+            |camera| {
+                camera.set_bounds(Rect::new(Point::ZERO, Vec2::new(500., 500.), 0.));
+                camera.follow((400., 400.), 0.5, 0.1);
+            },
+            |camera| camera.fit_rect(Rect::new(Point::ZERO, Vec2::new(200., 100.), 0.), 10.),
+            |camera| camera.clamp_zoom_to_bounds(),
+        ];
+        for mutation in mutations {
+            mutation(&mut camera);
+            assert_cache_is_fresh(&camera);
+        }
+    }
+
+    #[test]
+    fn direct_field_writes_dont_use_a_stale_cache() {
+        let mut camera = Camera::builder().build();
+        let point = Point::new(50., 80.);
+
+        camera.position.x += 100.;
+        camera.scale = Vec2::new(2., 2.);
+        camera.rotation = 0.5;
+
+        let fresh = camera.compute_matrix(camera.position);
+        assert_eq!(camera.to_matrix(), fresh);
+        assert_point_eq(camera.round_trip_world(point), point);
+
+        let mut rebuilt = Camera::new((0., 0.), 0.5, (2., 2.), (100., 0.), (1920., 1080.));
+        rebuilt.refresh_cache();
+        assert_eq!(
+            camera.world_to_screen_coords(point),
+            rebuilt.world_to_screen_coords(point)
+        );
+    }
+
+    #[test]
+    fn reset_restores_the_defaults() {
+        let mut camera = Camera::new((10., 10.), 1., (3., 3.), (-50., 50.), (800., 600.));
+        camera.set_bounds(Rect::new(Point::ZERO, Vec2::new(100., 100.), 0.));
+        camera.add_shake(1.);
+
+        let mut kept = camera;
+        camera.reset();
+        assert_eq!(camera, Camera::default());
+
+        kept.reset_keep_screen_size();
+        assert_eq!(kept.screen_size, Vec2::new(800., 600.));
+        assert_eq!(
+            kept,
+            Camera {
+                screen_size: Vec2::new(800., 600.),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn constant_velocity_without_damping() {
+        let mut camera = Camera::default();
+        camera.damping = 0.;
+        camera.set_velocity((10., -5.));
+
+        for _ in 0..4 {
+            camera.apply_velocity(0.5);
+        }
+        assert_point_eq(camera.position, Point::new(20., -10.));
+        assert_eq!(camera.velocity, Vec2::new(10., -5.));
+    }
+
+    #[test]
+    fn damping_decays_the_velocity() {
+        let mut camera = Camera::default();
+        camera.set_velocity((100., 0.));
+        camera.add_velocity((0., 50.));
+
+        let mut speed = camera.velocity.x.hypot(camera.velocity.y);
+        for _ in 0..600 {
+            camera.apply_velocity(1. / 60.);
+            let new_speed = camera.velocity.x.hypot(camera.velocity.y);
+            assert!(new_speed < speed);
+            speed = new_speed;
+        }
+        assert!(speed < 1e-9);
+        // The glide stops after a finite distance, the sum of the geometric series
+        let distance = 1. / 60. / (1. - (-4f64 / 60.).exp());
+        assert_point_eq(camera.position, Point::new(100. * distance, 50. * distance));
+    }
+
+    #[test]
+    fn no_movement_inside_the_deadzone() {
+        let mut camera = Camera::default();
+        let deadzone = Rect::new(Point::new(-100., -50.), Vec2::new(200., 100.), 0.);
+
+        camera.follow_with_deadzone((1000., 560.), deadzone);
+        camera.follow_with_deadzone((870., 495.), deadzone);
+        assert_eq!(camera.position, Point::ZERO);
+    }
+
+    #[test]
+    fn deadzone_edge_following_on_each_side() {
+        let deadzone = Rect::new(Point::new(-100., -50.), Vec2::new(200., 100.), 0.);
+        // (target, where it ends up relative to the screen center), the deadzone edge it left
+        let cases = [
+            ((1100., 540.), (100., 0.)),
+            ((800., 540.), (-100., 0.)),
+            ((960., 440.), (0., -50.)),
+            ((960., 650.), (0., 50.)),
+            ((1200., 300.), (100., -50.)),
+        ];
+
+        for (target, relative) in cases {
+            let mut camera = Camera::new((0., 0.), 0.3, (2., 2.), (0., 0.), (1920., 1080.));
+            let target = camera.screen_to_world_coords(target);
+            camera.follow_with_deadzone(target, deadzone);
+
+            let on_screen = camera.world_to_screen_coords(target);
+            assert_point_eq(on_screen, Point::new(960. + relative.0, 540. + relative.1));
+        }
+    }
+
+    #[test]
+    fn frustum_corners_unrotated() {
+        let mut camera = Camera::default();
+        camera.set_position((100., 50.));
+        camera.set_zoom((2., 2.));
+
+        let expected = [(100., 50.), (1060., 50.), (1060., 590.), (100., 590.)];
+        for (corner, expected) in camera.world_frustum_corners().into_iter().zip(expected) {
+            assert_point_eq(corner, expected.into());
+        }
+    }
+
+    #[test]
+    fn frustum_corners_rotated() {
+        let mut camera = Camera::default();
+        camera.set_rotation(PI / 2.);
+
+        // A screen point (x, y) comes from the world point (y, -x)
+        let expected = [(0., 0.), (0., -1920.), (1080., -1920.), (1080., 0.)];
+        for (corner, expected) in camera.world_frustum_corners().into_iter().zip(expected) {
+            assert_point_eq(corner, expected.into());
+        }
+    }
+
+    #[test]
+    fn normalize_rotation_wraps_into_range() {
+        let mut camera = Camera::default();
+
+        camera.rotate(3. * PI);
+        assert_eq!(camera.rotation, 3. * PI);
+        camera.normalize_rotation();
+        assert!((camera.rotation.abs() - PI).abs() < 1e-9);
+
+        camera.set_rotation(-3. * PI);
+        camera.normalize_rotation();
+        assert!((camera.rotation.abs() - PI).abs() < 1e-9);
+
+        camera.set_rotation(1.);
+        camera.normalize_rotation();
+        assert!((camera.rotation - 1.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn wrapped_setter() {
+        let mut camera = Camera::default();
+
+        camera.set_rotation_wrapped(2.5 * PI);
+        assert!((camera.rotation - PI / 2.).abs() < 1e-9);
+        camera.set_rotation_wrapped(-0.5);
+        assert!((camera.rotation + 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn zoom_steps_compose_exponentially() {
+        let camera = Camera::new((960., 540.), 0.2, (1., 1.), (30., 60.), (1920., 1080.));
+        let point = Point::new(400., 300.);
+
+        let mut single = camera;
+        single.zoom_steps_at_screen_coords(point, 1, 1.1);
+        single.zoom_steps_at_screen_coords(point, 1, 1.1);
+        let mut double = camera;
+        double.zoom_steps_at_screen_coords(point, 2, 1.1);
+        assert!(single.approx_eq(&double, EPSILON));
+
+        let mut out = camera;
+        out.zoom_steps_at_screen_coords(point, -3, 1.1);
+        assert!((out.scale.x - 1.1f64.powi(-3)).abs() < 1e-9);
+        assert_point_eq(
+            out.screen_to_world_coords(point),
+            camera.screen_to_world_coords(point),
+        );
+    }
+
+    #[cfg(feature = "ggez")]
+    #[test]
+    fn draw_param_round_trip() {
+        let camera = Camera::new((100., 50.), 0.5, (2., 2.), (0., 0.), (1920., 1080.));
+        let param: DrawParam = camera.into();
+
+        let decomposed = Camera::from_draw_param(&param).unwrap();
+        assert!(decomposed.approx_eq(&camera, 1e-3));
+
+        // The position ends up in the offset, the matrix is the same
+        let moved = Camera::new((100., 50.), -1.2, (3., 0.5), (40., -20.), (1920., 1080.));
+        let decomposed = Camera::from_draw_param(&moved.into()).unwrap();
+        assert_eq!(decomposed.position, Point::ZERO);
+        assert!(decomposed.to_matrix().abs_diff_eq(moved.to_matrix(), 1e-3));
+    }
+
+    #[cfg(feature = "ggez")]
+    #[test]
+    fn draw_param_values_are_decomposed() {
+        let param = DrawParam::default()
+            .dest([10., 20.])
+            .rotation(0.3)
+            .scale([2., 2.]);
+        let camera = Camera::from_draw_param(&param).unwrap();
+        assert_point_eq(camera.offset, Point::new(10., 20.));
+        assert!((camera.rotation - 0.3).abs() < 1e-6);
+
+        // An offset can't be represented
+        assert!(Camera::from_draw_param(&param.offset([0.5, 0.5])).is_none());
+    }
+
+    #[test]
+    fn float_conversions_match_the_point_ones() {
+        let camera = Camera::new((960., 540.), 0.9, (1.5, 2.), (-80., 35.), (1920., 1080.));
+
+        for (x, y) in [(0., 0.), (123.5, -45.), (-1000., 2000.)] {
+            let screen = camera.world_to_screen_coords((x, y));
+            assert_eq!(camera.world_to_screen(x, y), (screen.x, screen.y));
+
+            let world = camera.screen_to_world_coords((x, y));
+            assert_eq!(camera.screen_to_world(x, y), (world.x, world.y));
+        }
+    }
+
+    #[test]
+    fn world_lengths_scale_to_pixels() {
+        let camera = Camera::new((960., 540.), 0.8, (2., 3.), (10., 10.), (1920., 1080.));
+        let scale = camera.world_to_screen_scale();
+        let origin = Point::new(40., -25.);
+
+        let along_x = camera.screen_distance(origin, Point::new(origin.x + 10., origin.y));
+        let along_y = camera.screen_distance(origin, Point::new(origin.x, origin.y + 10.));
+        assert!((along_x - 10. * scale.x).abs() < 1e-3);
+        assert!((along_y - 10. * scale.y).abs() < 1e-3);
+        assert!((scale.x - 2.).abs() < 1e-6 && (scale.y - 3.).abs() < 1e-6);
+
+        let inverse = camera.screen_to_world_scale();
+        assert!((inverse.x - 0.5).abs() < 1e-6 && (inverse.y - 1. / 3.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn screen_space_camera_maps_points_to_themselves() {
+        let mut camera = Camera::screen_space((800., 600.));
+        camera.set_position((100., 200.));
+        camera.set_zoom((3., 3.));
+        camera.rotate(1.);
+
+        for point in [(0., 0.), (400., 300.), (-25.5, 1200.)] {
+            assert_point_eq(camera.world_to_screen_coords(point), point.into());
+            assert_point_eq(camera.screen_to_world_coords(point), point.into());
+        }
+    }
+
+    #[test]
+    fn smooth_zoom_endpoints() {
+        let mut camera = Camera::default();
+
+        camera.smooth_zoom_to((1., 1.), (3., 3.), 0., Easing::EaseInOut);
+        assert!((camera.scale.x - 1.).abs() < 1e-9);
+        camera.smooth_zoom_to((1., 1.), (3., 3.), 0.5, Easing::EaseInOut);
+        assert!((camera.scale.x - 2.).abs() < 1e-9);
+        camera.smooth_zoom_to((1., 1.), (3., 3.), 1., Easing::EaseInOut);
+        assert!((camera.scale.x - 3.).abs() < 1e-9);
+        assert!((camera.scale.y - 3.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn smooth_zoom_restarted_midway_starts_where_it_is() {
+        let mut camera = Camera::default();
+        camera.smooth_zoom_to((1., 1.), (3., 3.), 0.5, Easing::Linear);
+
+        // Abandoned for another target, the next transition starts from the current scale
+        let start = camera.scale;
+        camera.smooth_zoom_to(start, Vec2::new(0.5, 0.5), 0.1, Easing::Linear);
+        assert!((camera.scale.x - 1.85).abs() < 1e-9);
+    }
+
+    #[test]
+    fn overshooting_smooth_zoom_keeps_the_sign() {
+        let mut camera = Camera::default();
+
+        for step in 0..=10 {
+            camera.smooth_zoom_to(
+                (1., 1.),
+                (0.01, 0.01),
+                step as f64 / 10.,
+                crate::easing::elastic_out,
+            );
+            assert!(
+                camera.scale.x > 0. && camera.scale.y > 0.,
+                "{:?}",
+                camera.scale
+            );
+        }
+        assert!((camera.scale.x - 0.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn smooth_zoom_keeps_the_center_in_place() {
+        let mut camera = Camera::new((0., 0.), 0.4, (1., 1.), (100., 100.), (1920., 1080.));
+        let center = camera.view_center();
+
+        for step in 0..=10 {
+            camera.smooth_zoom_to((1., 1.), (0.25, 0.25), step as f64 / 10., Easing::EaseOut);
+            assert_point_eq(camera.view_center(), center);
+        }
+        assert!((camera.scale.x - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn view_center_after_center_on() {
+        let mut camera = Camera::new((200., 100.), -0.6, (1.75, 1.75), (0., 0.), (1280., 720.));
+        let point = Point::new(-300., 812.5);
+        camera.center_on(point);
+
+        assert_point_eq(camera.view_center(), point);
+    }
+
+    #[test]
+    fn zoom_keeps_a_flipped_axis_flipped() {
+        let mut camera = Camera::default();
+        camera.set_zoom((2., -2.));
+
+        camera.zoom((1.5, 1.5));
+        assert_eq!(camera.scale, Vec2::new(3., -3.));
+
+        camera.set_zoom_limits(Some(Vec2::new(1., 1.)), Some(Vec2::new(4., 4.)));
+        camera.zoom_uniform(2.);
+        assert_eq!(camera.scale, Vec2::new(4., -4.));
+        camera.zoom_at_screen_coords((100., 100.), (0.1, 0.1));
+        assert_eq!(camera.scale, Vec2::new(1., -1.));
+    }
+
+    #[test]
+    fn y_flip_around_the_view_center() {
+        let mut camera = Camera::default();
+        let center = camera.view_center();
+
+        camera.set_y_flip(true);
+        assert_eq!(camera.scale, Vec2::new(1., -1.));
+        assert_point_eq(camera.view_center(), center);
+        // 100 world units above the center is drawn 100 pixels below it
+        assert_point_eq(
+            camera.world_to_screen_coords((960., 440.)),
+            Point::new(960., 640.),
+        );
+        assert_point_eq(
+            camera.screen_to_world_coords((960., 640.)),
+            Point::new(960., 440.),
+        );
+
+        // Already flipped
+        camera.set_y_flip(true);
+        assert_eq!(camera.scale, Vec2::new(1., -1.));
+
+        camera.set_y_flip(false);
+        assert_eq!(camera.scale, Vec2::ONE);
+        assert_point_eq(camera.view_center(), center);
+    }
+
+    #[test]
+    fn try_screen_to_world_on_a_normal_camera() {
+        let camera = Camera::new((960., 540.), 0.3, (2., 2.), (10., 20.), (1920., 1080.));
+        let point = Point::new(300., 400.);
+
+        assert_eq!(
+            camera.try_screen_to_world_coords(point),
+            Some(camera.screen_to_world_coords(point))
+        );
+    }
+
+    #[test]
+    fn try_screen_to_world_on_a_singular_camera() {
+        // A zero sized window with a design resolution squashes the view to a point
+        let mut camera = Camera::default();
+        camera.set_design_resolution((1920., 1080.));
+        camera.set_screen_size((0., 0.));
+        assert_eq!(camera.try_screen_to_world_coords((10., 10.)), None);
+
+        let mut camera = Camera::default();
+        camera.rotation = f64::NAN;
+        assert_eq!(camera.try_screen_to_world_coords((10., 10.)), None);
+    }
+
+    #[test]
+    fn zero_scale_is_kept_invertible() {
+        let mut camera = Camera::default();
+        camera.set_zoom((0., 1.));
+        assert_eq!(camera.scale, Vec2::new(MIN_SCALE, 1.));
+        assert!(camera.try_screen_to_world_coords((10., 10.)).is_some());
+
+        // Written directly, the matrix floors it but there is no meaningful inverse
+        camera.scale = Vec2::new(0., 1.);
+        camera.refresh_cache();
+        let world = camera.screen_to_world_coords((10., 10.));
+        assert!(world.x.is_finite() && world.y.is_finite());
+        assert_eq!(camera.try_screen_to_world_coords((10., 10.)), None);
+    }
+
+    #[test]
+    fn rotational_shake_stays_within_max_angle() {
+        let mut camera = Camera::default();
+        camera.set_rotation(0.5);
+        camera.set_shake_config(ShakeConfig {
+            max_angle: 0.1,
+            ..Default::default()
+        });
+        camera.add_shake(1.);
+
+        let mut shook = false;
+        while camera.shake.trauma > 0. {
+            let angle = camera.shake.rotation().unwrap();
+            assert!(angle.abs() <= 0.1);
+            assert!((camera.view_rotation() - 0.5 - angle).abs() < 1e-12);
+            shook |= angle != 0.;
+            camera.update_shake(1. / 120.);
+        }
+        assert!(shook);
+    }
+
+    #[test]
+    fn zero_trauma_leaves_the_rotation_alone() {
+        let mut camera = Camera::default();
+        camera.set_rotation(0.5);
+        camera.set_shake_config(ShakeConfig {
+            max_angle: 0.1,
+            ..Default::default()
+        });
+        let steady = camera;
+
+        camera.add_shake(1.);
+        camera.update_shake(0.4);
+        camera.update_shake(0.7);
+
+        assert_eq!(camera.shake.rotation(), None);
+        assert_eq!(camera.view_rotation(), 0.5);
+        assert_eq!(camera.to_matrix(), steady.to_matrix());
+    }
+
+    #[test]
+    fn oriented_world_view_matches_the_frustum_corners() {
+        let camera = Camera::new((30., 20.), 0.6, (2., 2.), (100., -50.), (1280., 720.));
+        let view = camera.world_view_oriented();
+        assert!((view.width() - 640.).abs() < 1e-9 && (view.height() - 360.).abs() < 1e-9);
+
+        let corners = [
+            view.r_topleft(),
+            view.r_topright(),
+            view.r_botright(),
+            view.r_botleft(),
+        ];
+        let screen_corners = [(0., 0.), (1280., 0.), (1280., 720.), (0., 720.)];
+
+        for (corner, screen) in corners.into_iter().zip(screen_corners) {
+            assert_point_eq(corner, camera.screen_to_world_coords(screen));
+        }
+    }
+
+    #[test]
+    fn world_rect_to_screen_identity_and_zoomed() {
+        let rect = Rect::new(Point::new(10., 20.), Vec2::new(100., 50.), 0.);
+
+        let camera = Camera::default();
+        let screen = camera.world_rect_to_screen(rect);
+        assert_rect_eq(screen, Point::new(10., 20.), Vec2::new(100., 50.));
+        assert_rect_eq(
+            camera.screen_rect_to_world(screen),
+            Point::new(10., 20.),
+            Vec2::new(100., 50.),
+        );
+
+        let mut camera = Camera::default();
+        camera.set_position((5., 5.));
+        camera.set_zoom((2., 2.));
+        let screen = camera.world_rect_to_screen(rect);
+        assert_rect_eq(screen, Point::new(10., 30.), Vec2::new(200., 100.));
+        assert_rect_eq(
+            camera.screen_rect_to_world(screen),
+            Point::new(10., 20.),
+            Vec2::new(100., 50.),
+        );
+    }
+
+    #[test]
+    fn world_rect_to_screen_rotated_covers_every_corner() {
+        let camera = Camera::new((960., 540.), 0.7, (1.5, 1.5), (0., 0.), (1920., 1080.));
+        let rect = Rect::new(Point::new(-40., 60.), Vec2::new(300., 120.), 0.);
+        let screen = camera.world_rect_to_screen(rect);
+
+        for corner in rect_corners(&rect) {
+            let corner = camera.world_to_screen_coords(corner);
+            let point = Rect::new(corner, Vec2::ZERO, 0.);
+            assert_rect_contains(screen, point);
+        }
+    }
+
+    #[test]
+    fn pan_drag_keeps_the_grabbed_point_under_the_cursor() {
+        let mut camera = Camera::new((960., 540.), 0.5, (2., 1.5), (20., -30.), (1920., 1080.));
+        let cursor = Point::new(400., 300.);
+        let grabbed = camera.screen_to_world_coords(cursor);
+
+        camera.pan_drag((35., -20.));
+        assert_point_eq(
+            camera.world_to_screen_coords(grabbed),
+            Point::new(435., 280.),
+        );
+    }
+
+    #[test]
+    fn inverted_pan_drag_moves_content_the_other_way() {
+        let mut camera = Camera::new((960., 540.), 0.5, (2., 1.5), (20., -30.), (1920., 1080.));
+        let cursor = Point::new(400., 300.);
+        let grabbed = camera.screen_to_world_coords(cursor);
+
+        camera.pan_drag_inverted((35., -20.));
+        assert_point_eq(
+            camera.world_to_screen_coords(grabbed),
+            Point::new(365., 320.),
+        );
+    }
+
+    fn assert_translation_is_whole(camera: &Camera) {
+        let translation = camera.to_matrix().w_axis;
+
+        assert!((translation.x - translation.x.round()).abs() < 1e-3);
+        assert!((translation.y - translation.y.round()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn snap_to_pixel_at_1x() {
+        let mut camera = Camera::default();
+        camera.set_position((10., 20.));
+        camera.snap_to_pixel();
+        assert_point_eq(camera.position, Point::new(10., 20.));
+
+        camera.set_position((10.4, 20.6));
+        camera.snap_to_pixel();
+        assert_point_eq(camera.position, Point::new(10., 21.));
+        assert_translation_is_whole(&camera);
+    }
+
+    #[test]
+    fn snap_to_pixel_at_3x() {
+        let mut camera = Camera::default();
+        camera.set_zoom((3., 3.));
+        camera.set_position((10., 20.));
+        camera.snap_to_pixel();
+        assert_point_eq(camera.position, Point::new(10., 20.));
+
+        // The translation goes from -31.2 to -31, a third of a pixel is a world unit
+        camera.set_position((10.4, 20.6));
+        camera.snap_to_pixel();
+        assert_point_eq(camera.position, Point::new(31. / 3., 62. / 3.));
+        assert_translation_is_whole(&camera);
+    }
+
+    #[test]
+    fn snap_to_pixel_is_a_no_op_when_rotated() {
+        let mut camera = Camera::default();
+        camera.set_rotation(0.3);
+        camera.set_position((10.4, 20.6));
+        camera.snap_to_pixel();
+
+        assert_eq!(camera.position, Point::new(10.4, 20.6));
+    }
+
+    #[test]
+    fn automatic_pixel_snap() {
+        let mut camera = Camera::default();
+        camera.set_zoom((3., 3.));
+        camera.set_position((10.4, 20.6));
+        camera.set_pixel_snap(true);
+
+        assert_translation_is_whole(&camera);
+        assert_eq!(camera.position, Point::new(10.4, 20.6));
+    }
+
+    #[test]
+    fn polygon_projection_keeps_the_vertex_order() {
+        let camera = Camera::new((960., 540.), -0.4, (2., 2.), (15., 5.), (1920., 1080.));
+        let triangle = [
+            Point::new(0., 0.),
+            Point::new(100., 0.),
+            Point::new(50., 80.),
+        ];
+
+        let projected = camera.world_polygon_to_screen(&triangle);
+        assert_eq!(projected.len(), 3);
+        for (vertex, projected) in triangle.iter().zip(&projected) {
+            assert_eq!(*projected, camera.world_to_screen_coords(*vertex));
+        }
+    }
+
+    #[test]
+    fn circle_visibility() {
+        let camera = Camera::default();
+
+        assert!(camera.is_circle_visible((500., 500.), 10.));
+        assert!(!camera.is_circle_visible((-100., 500.), 50.));
+        assert!(!camera.is_circle_visible((2100., 1200.), 100.));
+        // Tangent to the left edge and to the bottom right corner
+        assert!(camera.is_circle_visible((-50., 500.), 50.));
+        assert!(camera.is_circle_visible((1950., 1120.), 50.));
+        // Partly visible, the center is off screen
+        assert!(camera.is_circle_visible((-30., -30.), 50.));
+    }
+
+    #[test]
+    fn circle_visibility_uses_the_larger_zoom() {
+        let mut camera = Camera::default();
+        camera.set_zoom((1., 3.));
+
+        // Drawn at x = -20 with a radius of 30 pixels, 10 would cull it
+        assert!(camera.is_circle_visible((-20., 100.), 10.));
+        assert!(!camera.is_circle_visible((-40., 100.), 10.));
+    }
+
+    #[test]
+    fn zoom_to_fit_width_and_height() {
+        let mut camera = Camera::default();
+        camera.set_zoom((1.5, 2.5));
+
+        assert_eq!(camera.zoom_to_fit_width(1000.), 1920. / 1000.);
+        assert_eq!(camera.zoom_to_fit_height(540.), 2.);
+        // Guarded, the current scale comes back
+        assert_eq!(camera.zoom_to_fit_width(0.), 1.5);
+        assert_eq!(camera.zoom_to_fit_height(-5.), 2.5);
+
+        camera.set_zoom_uniform(camera.zoom_to_fit_width(1000.));
+        assert!((camera.world_view().width() - 1000.).abs() < EPSILON);
+    }
+
+    #[test]
+    fn rotating_around_the_center_keeps_the_view_center() {
+        let mut camera = Camera::new((200., 150.), 0.1, (0.75, 0.75), (-60., 300.), (1280., 720.));
+        let center = camera.view_center();
+
+        for _ in 0..12 {
+            camera.rotate_around_center(0.5);
+            assert_point_eq(camera.view_center(), center);
+        }
+    }
+
+    #[test]
+    fn rotation_limits_clamp_exactly() {
+        let mut camera = Camera::default();
+        camera.set_rotation_limits(Some((-0.3, 0.3)));
+
+        camera.rotate(1.);
+        assert_eq!(camera.rotation, 0.3);
+        camera.rotate(-5.);
+        assert_eq!(camera.rotation, -0.3);
+        camera.set_rotation(0.1);
+        assert_eq!(camera.rotation, 0.1);
+        camera.set_rotation(2.);
+        assert_eq!(camera.rotation, 0.3);
+    }
+
+    #[test]
+    fn no_rotation_limits_keep_it_unbounded() {
+        let mut camera = Camera::default();
+        camera.set_rotation(1.);
+        camera.set_rotation_limits(None);
+
+        camera.rotate(10.);
+        assert_eq!(camera.rotation, 11.);
+    }
+
+    #[test]
+    fn inverted_rotation_limits_dont_panic() {
+        let mut camera = Camera::default();
+        camera.set_rotation_limits(Some((0.3, -0.3)));
+
+        camera.set_rotation(0.);
+        assert_eq!(camera.rotation, -0.3);
+    }
+
+    #[test]
+    fn uniform_zoom_on_equal_and_unequal_axes() {
+        let mut camera = Camera::default();
+        camera.set_zoom((2., 2.));
+        assert_eq!(camera.uniform_zoom(), Some(2.));
+        assert_eq!(camera.uniform_zoom_or(1.), 2.);
+
+        camera.set_zoom((2., 3.));
+        assert_eq!(camera.uniform_zoom(), None);
+        assert_eq!(camera.uniform_zoom_or(1.), 1.);
+    }
+
+    #[test]
+    fn from_view_rect_contains_and_centers_the_rect() {
+        let rect = Rect::new(Point::new(100., 200.), Vec2::new(800., 300.), 0.);
+        let camera = Camera::from_view_rect(rect, (1280., 720.));
+
+        // Contained, limited by the width
+        assert!((camera.scale.x - 1.6).abs() < 1e-9);
+        assert_eq!(camera.screen_size, Vec2::new(1280., 720.));
+        assert_rect_contains(camera.world_view(), rect);
+        assert!((camera.world_view().width() - 800.).abs() < EPSILON);
+        assert_point_eq(camera.view_center(), Point::new(500., 350.));
+    }
+
+    #[cfg(feature = "ggez")]
+    #[test]
+    fn debug_mesh_builds_headless() {
+        for rotation in [0., 0.8] {
+            let mut camera = Camera::default();
+            camera.set_rotation(rotation);
+            camera.set_zoom((2., 2.));
+
+            let builder = camera.debug_mesh(Color::WHITE).unwrap();
+            let data = builder.build();
+            assert!(!data.vertices.is_empty());
+            assert!(!data.indices.is_empty());
+            assert_eq!(data.indices.len() % 3, 0);
+        }
+    }
+
+    #[test]
+    fn vectors_are_rotated_and_scaled_only() {
+        let camera = Camera::new((50., 50.), PI / 2., (2., 2.), (100., 100.), (1920., 1080.));
+
+        let screen = camera.world_to_screen_vector((1., 0.));
+        assert_point_eq(Point::new(screen.x, screen.y), Point::new(0., 2.));
+        let world = camera.screen_to_world_vector((0., 2.));
+        assert_point_eq(Point::new(world.x, world.y), Point::new(1., 0.));
+
+        // No translation
+        let zero = camera.world_to_screen_vector((0., 0.));
+        assert_eq!((zero.x, zero.y), (0., 0.));
+        let zero = camera.screen_to_world_vector((0., 0.));
+        assert_eq!((zero.x, zero.y), (0., 0.));
+    }
+
+    #[test]
+    fn vectors_match_the_difference_of_points() {
+        let camera = Camera::new((300., 200.), -0.7, (1.5, 0.5), (-20., 40.), (1920., 1080.));
+        let (point, vector) = (Point::new(10., 20.), Vec2::new(30., -15.));
+
+        let a = camera.world_to_screen_coords(point);
+        let b = camera.world_to_screen_coords(Point::new(point.x + vector.x, point.y + vector.y));
+        let screen = camera.world_to_screen_vector(vector);
+        assert_point_eq(
+            Point::new(b.x - a.x, b.y - a.y),
+            Point::new(screen.x, screen.y),
+        );
+    }
+
+    #[test]
+    fn dpi_scale_of_one_changes_nothing() {
+        let mut camera = Camera::new((960., 540.), 0.3, (2., 2.), (40., 20.), (1920., 1080.));
+        let matrix = camera.to_matrix();
+        camera.set_dpi_scale(1.);
+
+        assert_eq!(camera.to_matrix(), matrix);
+        assert_eq!(camera.to_inverse_matrix(), matrix.inverse());
+    }
+
+    #[test]
+    fn dpi_scale_of_two() {
+        let logical = Camera::new((960., 540.), 0.3, (2., 2.), (40., 20.), (1920., 1080.));
+        let mut camera = logical;
+        camera.set_dpi_scale(2.);
+        let point = Point::new(123., -45.);
+
+        // Pointer coordinates and the view stay logical
+        assert_eq!(
+            camera.world_to_screen_coords(point),
+            logical.world_to_screen_coords(point)
+        );
+        assert_eq!(
+            camera.screen_to_world_coords(point),
+            logical.screen_to_world_coords(point)
+        );
+        assert_rect_eq(
+            camera.world_view(),
+            logical.world_view().aa_topleft(),
+            Vec2::new(logical.world_view().width(), logical.world_view().height()),
+        );
+
+        // Rendering is in physical pixels
+        let world = Vec3::new(point.x as f32, point.y as f32, 0.);
+        let physical = camera.to_matrix().transform_point3(world);
+        let screen = logical.world_to_screen_coords(point);
+        assert_point_eq(
+            Point::new(physical.x as f64, physical.y as f64),
+            Point::new(screen.x * 2., screen.y * 2.),
+        );
+        assert!(camera
+            .to_matrix()
+            .mul_mat4(&camera.to_inverse_matrix())
+            .abs_diff_eq(Mat4::IDENTITY, 1e-4));
+    }
+
+    #[test]
+    fn layer_matrix_at_full_depth_is_apply_matrix() {
+        let camera = Camera::new((960., 540.), 0.3, (2., 2.), (400., -120.), (1920., 1080.));
+        let object = Transform {
+            dest: Point::new(50., 25.),
+            rotation: 1.,
+            scale: Vec2::new(0.5, 0.5),
+            offset: Point::ZERO,
+        };
+
+        assert_eq!(camera.layer_matrix(1., object), camera.apply_matrix(object));
+        assert_eq!(
+            camera.layer_matrix(0.5, object),
+            camera.to_matrix_parallax(0.5).mul_mat4(&object.to_matrix())
+        );
+    }
+
+    #[test]
+    fn same_seed_replays_the_same_shake() {
+        let mut a = Camera::default();
+        let mut b = Camera::default();
+        a.set_shake_seed(42);
+        b.set_shake_seed(42);
+        a.add_shake(1.);
+        b.add_shake(1.);
+
+        for dt in [0.016, 0.02, 0.033, 0.016, 0.1, 0.05] {
+            a.update_shake(dt);
+            b.update_shake(dt);
+
+            assert_eq!(a.shake.offset(), b.shake.offset());
+            assert_eq!(a.shake.rotation(), b.shake.rotation());
+            assert_eq!(a.to_matrix(), b.to_matrix());
+        }
+    }
+
+    #[test]
+    fn other_seeds_shake_differently() {
+        let mut a = Camera::default();
+        let mut b = Camera::default();
+        a.set_shake_seed(1);
+        b.set_shake_seed(2);
+        a.add_shake(1.);
+        b.add_shake(1.);
+        a.update_shake(0.1);
+        b.update_shake(0.1);
+
+        assert_ne!(a.shake.offset(), b.shake.offset());
+    }
+
+    #[test]
+    fn expanded_world_view_grows_by_the_margin() {
+        let camera = Camera::new((960., 540.), 0.4, (2.5, 2.5), (-75., 30.), (1920., 1080.));
+        let view = camera.world_view();
+        let expanded = camera.world_view_expanded(25.);
+
+        let (topleft, expanded_topleft) = (view.aa_topleft(), expanded.aa_topleft());
+        assert!((expanded_topleft.x - (topleft.x - 25.)).abs() < 1e-9);
+        assert!((expanded_topleft.y - (topleft.y - 25.)).abs() < 1e-9);
+        assert!((expanded.width() - (view.width() + 50.)).abs() < 1e-9);
+        assert!((expanded.height() - (view.height() + 50.)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn world_aabb_takes_each_bound_from_its_own_corner() {
+        let mut camera = Camera::default();
+        camera.set_rotation(PI / 4.);
+        let [topleft, topright, botright, botleft] = camera.world_frustum_corners();
+
+        let (min, max) = camera.world_aabb();
+        assert_eq!(min, Point::new(topleft.x, topright.y));
+        assert_eq!(max, Point::new(botright.x, botleft.y));
+    }
+
+    #[test]
+    fn world_aabb_turned_135_degrees() {
+        let mut camera = Camera::default();
+        camera.rotate_around_center(PI * 0.75);
+        let [topleft, topright, botright, botleft] = camera.world_frustum_corners();
+
+        // The top-left screen corner now gives the bottom of the view
+        let (min, max) = camera.world_aabb();
+        assert_eq!(min, Point::new(topright.x, botright.y));
+        assert_eq!(max, Point::new(botleft.x, topleft.y));
+    }
+
+    fn assert_round_trips(camera: &Camera, screen_points: &[Point]) {
+        for screen in screen_points {
+            let world = camera.screen_to_world_coords(*screen);
+            assert_point_eq(camera.world_to_screen_coords(world), *screen);
+            assert_point_eq(camera.round_trip_world(world), world);
+        }
+    }
+
+    #[test]
+    fn left_half_viewport() {
+        let mut camera = Camera::new((0., 0.), 0.2, (1.5, 1.5), (10., 10.), (1920., 1080.));
+        camera.set_viewport(Rect::new(Point::ZERO, Vec2::new(960., 1080.), 0.));
+
+        assert_point_eq(camera.screen_center(), Point::new(480., 540.));
+        assert!(!camera.is_point_visible(camera.screen_to_world_coords((1500., 500.))));
+        assert_round_trips(
+            &camera,
+            &[
+                Point::new(10., 10.),
+                Point::new(480., 540.),
+                Point::new(900., 1000.),
+            ],
+        );
+    }
+
+    #[test]
+    fn bottom_right_quadrant_viewport() {
+        let mut camera = Camera::default();
+        camera.set_viewport(Rect::new(Point::new(960., 540.), Vec2::new(960., 540.), 0.));
+
+        assert_point_eq(camera.origin_on_screen(), Point::new(960., 540.));
+        assert_point_eq(
+            camera.screen_to_world_coords((1060., 640.)),
+            Point::new(100., 100.),
+        );
+        assert_round_trips(&camera, &[Point::new(1000., 600.), Point::new(1440., 810.)]);
+    }
+
+    #[test]
+    fn full_screen_viewport_changes_nothing() {
+        let mut camera = Camera::new((960., 540.), 0.5, (2., 2.), (40., 20.), (1920., 1080.));
+        let matrix = camera.to_matrix();
+        camera.set_viewport(Rect::new(Point::ZERO, Vec2::new(1920., 1080.), 0.));
+
+        assert_eq!(camera.to_matrix(), matrix);
+    }
+
+    #[test]
+    fn view_rotation_is_clockwise_on_screen() {
+        for rotation in [0., 0.5, PI / 2., -1., 3., 7.] {
+            let mut camera = Camera::default();
+            camera.set_rotation(rotation);
+
+            // Clockwise angle from screen up to the drawn world-up arrow
+            let up = camera.world_to_screen_vector((0., -1.));
+            let on_screen = up.x.atan2(-up.y);
+
+            assert_eq!(camera.view_rotation(), rotation);
+            assert!((camera.view_rotation_normalized() - on_screen).abs() < 1e-4);
+            assert!((-PI..=PI).contains(&camera.view_rotation_normalized()));
+
+            // World up is +y once mirrored, and it turns the other way
+            camera.set_orientation(Orientation::YUp);
+            let up = camera.world_to_screen_vector((0., 1.));
+            let on_screen = up.x.atan2(-up.y);
+
+            assert_eq!(camera.view_rotation(), -rotation);
+            assert!((camera.view_rotation_normalized() - on_screen).abs() < 1e-4);
+            assert_eq!(camera.counter_rotation(), wrap_angle(-rotation));
+        }
+    }
+
+    #[test]
+    fn nesting_in_a_default_parent_gives_the_child_matrix() {
+        let child = Camera::new((100., 80.), 0.6, (0.5, 0.5), (300., -20.), (400., 300.));
+
+        assert!(Camera::default()
+            .nested(&child)
+            .abs_diff_eq(child.to_matrix(), 1e-6));
+    }
+
+    #[test]
+    fn nesting_goes_through_the_parent() {
+        let parent = Camera::new((0., 0.), 0., (2., 2.), (-50., -50.), (1920., 1080.));
+        let child = Camera::new((100., 80.), 0.6, (0.5, 0.5), (300., -20.), (400., 300.));
+        let world = Point::new(320., 10.);
+
+        let on_child = child.world_to_screen_coords(world);
+        let expected = parent.world_to_screen_coords(on_child);
+        let nested =
+            parent
+                .nested(&child)
+                .transform_point3(Vec3::new(world.x as f32, world.y as f32, 0.));
+        assert_point_eq(Point::new(nested.x as f64, nested.y as f64), expected);
+    }
+
+    #[test]
+    fn position_limits_on_x_only() {
+        let mut camera = Camera::default();
+        camera.set_position_limits((0., f64::NEG_INFINITY), (100., f64::INFINITY));
+
+        camera.set_position((-50., 1e6));
+        assert_eq!(camera.position, Point::new(0., 1e6));
+        camera.move_by_world_coords((-500., 2e6));
+        assert_eq!(camera.position, Point::new(100., -1e6));
+    }
+
+    #[test]
+    fn position_limits_on_both_axes() {
+        let mut camera = Camera::default();
+        camera.set_position((500., 500.));
+        camera.set_position_limits((0., 0.), (100., 50.));
+        assert_eq!(camera.position, Point::new(100., 50.));
+
+        camera.set_position((200., -10.));
+        assert_eq!(camera.position, Point::new(100., 0.));
+        camera.follow((-40., 20.), 1., 1. / 60.);
+        assert_eq!(camera.position, Point::new(0., 20.));
+        camera.center_on((1000., 1000.));
+        assert_eq!(camera.position, Point::new(100., 50.));
+    }
+
+    #[test]
+    fn visible_world_size_follows_the_zoom() {
+        let mut camera = Camera::default();
+        assert_eq!(camera.visible_world_size(), Vec2::new(1920., 1080.));
+
+        camera.set_zoom_uniform(2.);
+        assert_eq!(camera.visible_world_size(), Vec2::new(960., 540.));
+
+        // The extent before rotating, unlike world_view
+        camera.set_rotation(0.7);
+        assert_eq!(camera.visible_world_size(), Vec2::new(960., 540.));
+    }
+
+    #[test]
+    fn origin_on_screen_matches_a_manual_conversion() {
+        let camera = Camera::new((10., 10.), 0., (2., 2.), (100., 50.), (1920., 1080.));
+
+        assert_eq!(
+            camera.origin_on_screen(),
+            camera.world_to_screen_coords((0., 0.))
+        );
+        assert_point_eq(camera.origin_on_screen(), Point::new(-190., -90.));
+    }
+
+    #[cfg(feature = "ggez")]
+    fn param_matrix(param: DrawParam) -> Mat4 {
+        match param.transform {
+            graphics::Transform::Matrix(matrix) => matrix.into(),
+            graphics::Transform::Values { .. } => panic!("the camera transforms are matrices"),
+        }
+    }
+
+    #[cfg(feature = "ggez")]
+    #[test]
+    fn world_draw_param_is_apply_matrix() {
+        let camera = Camera::new((960., 540.), 0.3, (2., 2.), (40., 20.), (1920., 1080.));
+        let transform = Transform {
+            dest: Point::new(50., 25.),
+            rotation: 1.,
+            scale: Vec2::new(0.5, 0.5),
+            offset: Point::new(8., 8.),
+        };
+
+        assert_eq!(
+            param_matrix(camera.world_draw_param(transform)),
+            camera.apply_matrix(transform)
+        );
+    }
+
+    #[test]
+    fn zoom_at_world_coords_keeps_the_point_on_screen() {
+        let mut camera = Camera::new((960., 540.), 0.6, (1.25, 1.25), (80., -30.), (1920., 1080.));
+        let world = Point::new(300., -120.);
+        let on_screen = camera.world_to_screen_coords(world);
+
+        camera.zoom_at_world_coords(world, (1.7, 1.7));
+        assert!((camera.scale.x - 1.25 * 1.7).abs() < 1e-9);
+        assert_point_eq(camera.world_to_screen_coords(world), on_screen);
+
+        camera.zoom_at_world_coords(world, (0.5, 2.));
+        assert_point_eq(camera.world_to_screen_coords(world), on_screen);
+    }
+
+    #[test]
+    fn scoped_screen_size_leaves_the_original_alone() {
+        let camera = Camera::default();
+        let scoped = camera.with_screen_size_scoped((640., 480.));
+
+        assert_eq!(camera.screen_size, Vec2::new(1920., 1080.));
+        assert_rect_eq(camera.world_view(), Point::ZERO, Vec2::new(1920., 1080.));
+        assert_eq!(scoped.screen_size, Vec2::new(640., 480.));
+        assert_rect_eq(scoped.world_view(), Point::ZERO, Vec2::new(640., 480.));
+    }
+
+    #[test]
+    fn set_offset_multiplies_by_the_scale() {
+        let mut camera = Camera::default();
+        camera.set_zoom((2., 2.));
+
+        camera.set_offset((10., 5.));
+        assert_eq!(camera.offset, Point::new(20., 10.));
+    }
+
+    #[test]
+    fn offset_by_adds_the_delta_as_is() {
+        let mut camera = Camera::default();
+        camera.set_zoom((2., 2.));
+        camera.set_offset((10., 5.));
+
+        camera.offset_by((3., 4.));
+        assert_eq!(camera.offset, Point::new(23., 14.));
+        camera.offset_by((-23., -14.));
+        assert_eq!(camera.offset, Point::ZERO);
+    }
+
+    #[test]
+    fn get_offset_gives_set_offset_back_scaled() {
+        let mut camera = Camera::default();
+        camera.set_zoom((2., 3.));
+
+        camera.set_offset((10., 10.));
+        assert_eq!(camera.get_offset(), Point::new(20., 30.));
+    }
+
+    #[test]
+    fn set_offset_raw_round_trips() {
+        let mut camera = Camera::default();
+        camera.set_zoom((2., 3.));
+
+        camera.set_offset_raw((10., 10.));
+        assert_eq!(camera.get_offset(), Point::new(10., 10.));
+        assert_point_eq(
+            camera.world_to_screen_coords((0., 0.)),
+            Point::new(10., 10.),
+        );
+    }
+
+    #[cfg(feature = "ggez")]
+    #[test]
+    fn world_sprite_param_covers_the_world_size() {
+        let mut camera = Camera::default();
+        camera.set_zoom((2., 2.));
+        // Each argument can be its own type
+        let param = camera.world_sprite_param(
+            Point::new(300., 200.),
+            (50., 20.),
+            Vec2::new(200., 80.),
+            (0.5, 0.5),
+        );
+        let matrix = param_matrix(param);
+        let texture_to_screen =
+            |x: f32, y: f32| matrix.transform_point3(Vec3::new(x, y, 0.)).truncate();
+
+        let topleft = texture_to_screen(0., 0.);
+        let botright = texture_to_screen(200., 80.);
+        assert!(((botright.x - topleft.x) - 100.).abs() < 1e-3);
+        assert!(((botright.y - topleft.y) - 40.).abs() < 1e-3);
+
+        // The origin is the texture center, which lands on dest
+        let center = texture_to_screen(100., 40.);
+        assert_point_eq(
+            Point::new(center.x as f64, center.y as f64),
+            camera.world_to_screen_coords((300., 200.)),
+        );
+    }
+
+    #[test]
+    fn follow_dynamic_centers_a_stationary_target() {
+        let mut camera = Camera::default();
+        let target = Point::new(500., 300.);
+
+        for _ in 0..600 {
+            camera.follow_dynamic(target, (0., 0.), DynamicFollowConfig::default(), 1. / 60.);
+        }
+        assert!((camera.scale.x - 1.).abs() < 1e-9);
+        assert_point_eq(camera.view_center(), target);
+    }
+
+    #[test]
+    fn follow_dynamic_zooms_out_for_a_fast_target() {
+        let mut camera = Camera::default();
+        let target = Point::new(500., 300.);
+
+        for _ in 0..600 {
+            camera.follow_dynamic(
+                target,
+                (5000., 0.),
+                DynamicFollowConfig::default(),
+                1. / 60.,
+            );
+        }
+        assert!((camera.scale.x - 0.5).abs() < 1e-3);
+        assert!((camera.scale.y - 0.5).abs() < 1e-3);
+        assert_point_eq(camera.view_center(), target);
+    }
+
+    #[test]
+    fn clamp_zoom_to_bounds_zooms_in_to_fit() {
+        let mut camera = Camera::default();
+        camera.set_zoom((0.25, 0.25));
+        camera.set_bounds(Rect::new(Point::ZERO, Vec2::new(3840., 2160.), 0.));
+
+        camera.clamp_zoom_to_bounds();
+        assert_point_eq(
+            Point::new(camera.scale.x, camera.scale.y),
+            Point::new(0.5, 0.5),
+        );
+        assert_rect_contains(camera.bounds.unwrap(), camera.world_view());
+    }
+
+    #[test]
+    fn clamp_zoom_to_bounds_uses_the_tighter_axis() {
+        let mut camera = Camera::default();
+        camera.set_zoom((0.25, 0.25));
+        camera.set_bounds(Rect::new(Point::ZERO, Vec2::new(3840., 1080.), 0.));
+
+        camera.clamp_zoom_to_bounds();
+        assert_point_eq(
+            Point::new(camera.scale.x, camera.scale.y),
+            Point::new(1., 1.),
+        );
+        assert_rect_contains(camera.bounds.unwrap(), camera.world_view());
+    }
+
+    #[test]
+    fn clamp_zoom_to_bounds_keeps_the_axes_apart() {
+        let mut camera = Camera::default();
+        camera.set_zoom((0.25, 0.5));
+        camera.set_bounds(Rect::new(Point::ZERO, Vec2::new(3840., 1080.), 0.));
+
+        camera.clamp_zoom_to_bounds();
+        assert_point_eq(
+            Point::new(camera.scale.x, camera.scale.y),
+            Point::new(0.5, 1.),
+        );
+        assert_rect_contains(camera.bounds.unwrap(), camera.world_view());
+    }
+
+    #[test]
+    fn clamp_zoom_to_bounds_never_zooms_out() {
+        let mut camera = Camera::default();
+        camera.set_zoom((2., 2.));
+        camera.set_bounds(Rect::new(Point::ZERO, Vec2::new(3840., 2160.), 0.));
+
+        camera.clamp_zoom_to_bounds();
+        assert_eq!(camera.scale, Vec2::new(2., 2.));
+    }
+
+    #[test]
+    fn matrix_arrays_are_column_major() {
+        let camera = Camera::new((960., 540.), 0.4, (2., 2.), (40., 20.), (1920., 1080.));
+        let array = camera.to_matrix_array();
+
+        assert_eq!(array, camera.to_matrix().to_cols_array());
+        assert_eq!(
+            camera.to_inverse_matrix_array(),
+            camera.to_inverse_matrix().to_cols_array()
+        );
+
+        // The translation is the last column
+        let unrotated = Camera::new((960., 540.), 0., (2., 2.), (40., 20.), (1920., 1080.));
+        let array = unrotated.to_matrix_array();
+        assert_eq!((array[12], array[13]), (880., 500.));
+        assert_eq!((array[0], array[5], array[15]), (2., 2., 1.));
+    }
+
+    #[test]
+    fn edge_scroll_moves_towards_the_edge() {
+        let cases = [
+            (Point::new(0., 540.), Point::new(-100., 0.)),
+            (Point::new(1920., 540.), Point::new(100., 0.)),
+            (Point::new(960., 0.), Point::new(0., -100.)),
+            (Point::new(960., 1080.), Point::new(0., 100.)),
+            // Halfway through the margin, half the speed
+            (Point::new(25., 540.), Point::new(-50., 0.)),
+        ];
+
+        for (cursor, moved) in cases {
+            let mut camera = Camera::default();
+            camera.edge_scroll(cursor, 50., 100., 1.);
+            assert_point_eq(camera.position, moved);
+        }
+    }
+
+    #[test]
+    fn edge_scroll_ignores_the_middle_of_the_screen() {
+        let mut camera = Camera::default();
+
+        camera.edge_scroll((960., 540.), 50., 100., 1.);
+        assert_eq!(camera.position, Point::ZERO);
+    }
+
+    #[test]
+    fn edge_scroll_respects_the_position_limits() {
+        let mut camera = Camera::default();
+        camera.set_position_limits((-30., -30.), (30., 30.));
+
+        for _ in 0..10 {
+            camera.edge_scroll((0., 0.), 50., 100., 1.);
+        }
+        assert_point_eq(camera.position, Point::new(-30., -30.));
+    }
+
+    #[test]
+    fn origin_visible_follows_the_position() {
+        let mut camera = Camera::default();
+
+        camera.set_position((-100., -100.));
+        assert!(camera.origin_visible());
+        camera.set_position((100., 100.));
+        assert!(!camera.origin_visible());
+        camera.set_position((-100., -100.));
+        assert!(camera.origin_visible());
+    }
+
+    #[test]
+    fn inverse_matrix_undoes_to_matrix() {
+        let mut camera = Camera::new((960., 540.), 0.7, (1.5, 0.75), (300., -80.), (1920., 1080.));
+        camera.refresh_cache();
+
+        for dpi_scale in [1., 1.5, 2.] {
+            camera.set_dpi_scale(dpi_scale);
+            assert!(camera
+                .to_matrix()
+                .mul_mat4(&camera.to_inverse_matrix())
+                .abs_diff_eq(Mat4::IDENTITY, 1e-4));
+        }
+    }
+
+    #[test]
+    fn inverse_matrix_comes_from_the_cache() {
+        let mut camera = Camera::new((960., 540.), 0.7, (1.5, 0.75), (300., -80.), (1920., 1080.));
+        camera.refresh_cache();
+
+        assert_eq!(
+            camera.to_inverse_matrix(),
+            camera.compute_matrix(camera.position).inverse()
+        );
+    }
+
+    #[test]
+    fn every_zoom_anchor_stays_in_place() {
+        let camera = Camera::new((300., 200.), 0.5, (1.5, 1.5), (50., -20.), (1920., 1080.));
+        let screen_point = Point::new(1500., 900.);
+        let world_point = Point::new(-200., 400.);
+
+        let mut zoomed = camera;
+        zoomed.zoom_with_anchor((2., 2.), ZoomAnchor::Center);
+        assert_point_eq(zoomed.view_center(), camera.view_center());
+
+        let mut zoomed = camera;
+        zoomed.zoom_with_anchor((2., 2.), ZoomAnchor::ScreenPoint(screen_point));
+        assert_point_eq(
+            zoomed.screen_to_world_coords(screen_point),
+            camera.screen_to_world_coords(screen_point),
+        );
+
+        let mut zoomed = camera;
+        zoomed.zoom_with_anchor((2., 2.), ZoomAnchor::WorldPoint(world_point));
+        assert_point_eq(
+            zoomed.world_to_screen_coords(world_point),
+            camera.world_to_screen_coords(world_point),
+        );
+
+        let mut zoomed = camera;
+        zoomed.zoom_with_anchor((2., 2.), ZoomAnchor::Origin);
+        assert_eq!(zoomed.position, camera.position);
+        assert_point_eq(
+            zoomed.world_to_screen_coords(camera.position),
+            camera.offset,
+        );
+        assert_eq!(zoomed.scale, Vec2::new(3., 3.));
+    }
+
+    #[test]
+    fn zoom_wrappers_match_their_anchor() {
+        let camera = Camera::new((300., 200.), 0.5, (1.5, 1.5), (50., -20.), (1920., 1080.));
+        let screen_point = Point::new(1500., 900.);
+
+        let (mut wrapper, mut anchored) = (camera, camera);
+        wrapper.zoom((2., 2.));
+        anchored.zoom_with_anchor((2., 2.), ZoomAnchor::Origin);
+        assert_eq!(wrapper, anchored);
+
+        let (mut wrapper, mut anchored) = (camera, camera);
+        wrapper.zoom_at_screen_coords(screen_point, (2., 2.));
+        anchored.zoom_with_anchor((2., 2.), ZoomAnchor::ScreenPoint(screen_point));
+        assert_eq!(wrapper, anchored);
+    }
+
+    #[test]
+    fn screen_distance_scales_with_the_zoom() {
+        let mut camera = Camera::default();
+        let (a, b) = (Point::new(10., 10.), Point::new(40., 50.));
+
+        assert!((camera.world_distance(a, b) - 50.).abs() < 1e-9);
+        assert!((camera.screen_distance(a, b) - 50.).abs() < EPSILON);
+
+        camera.set_zoom((2., 2.));
+        assert!((camera.world_distance(a, b) - 50.).abs() < 1e-9);
+        assert!((camera.screen_distance(a, b) - 100.).abs() < EPSILON);
+    }
+
+    #[test]
+    fn stabilize_hides_sub_step_movement() {
+        let translation = |stabilize: bool, x: f64| {
+            let mut camera = Camera::default();
+            camera.set_stabilize(stabilize);
+            camera.set_position((x, 0.));
+            camera.to_matrix().w_axis.x
+        };
+
+        assert_eq!(translation(true, 10.), translation(true, 10.02));
+        assert_ne!(translation(false, 10.), translation(false, 10.02));
+    }
+
+    #[test]
+    fn stabilize_quantizes_rotated_cameras() {
+        let mut camera = Camera::new(
+            (960., 540.),
+            0.6,
+            (1.3, 1.3),
+            (123.45, -67.89),
+            (1920., 1080.),
+        );
+        camera.set_stabilize(true);
+
+        let translation = camera.to_matrix().w_axis;
+        for value in [translation.x, translation.y] {
+            let steps = value / 0.125;
+            assert!(
+                (steps - steps.round()).abs() < 1e-2,
+                "{value} isn't quantized"
+            );
+        }
+    }
+
+    #[test]
+    fn visible_tile_range_covers_the_view() {
+        let mut camera = Camera::default();
+        assert_eq!(camera.visible_tile_range((64., 64.)), (0, 0, 29, 16));
+
+        // Negative coordinates are floored
+        camera.set_position((-100., -50.));
+        assert_eq!(camera.visible_tile_range((64., 64.)), (-2, -1, 28, 16));
+    }
+
+    #[test]
+    fn look_direction_turns_the_view() {
+        let mut camera = Camera::default();
+
+        camera.look_direction((0., -1.));
+        assert!(camera.rotation.abs() < 1e-9);
+        camera.look_direction((1., 0.));
+        assert!((camera.rotation + PI / 2.).abs() < 1e-9);
+
+        // A zero direction is ignored
+        camera.look_direction((0., 0.));
+        assert!((camera.rotation + PI / 2.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn looked_direction_points_up_on_screen() {
+        for orientation in [Orientation::YDown, Orientation::YUp] {
+            for direction in [(1., 0.), (0.6, 0.8), (-3., 1.), (-0.2, -5.)] {
+                let mut camera = Camera::default();
+                camera.set_orientation(orientation);
+                let center = camera.view_center();
+
+                camera.look_direction(direction);
+                let on_screen = camera.world_to_screen_vector(direction);
+                assert!(
+                    on_screen.x.abs() < 1e-3,
+                    "{direction:?} gives {on_screen:?}"
+                );
+                assert!(on_screen.y < 0., "{direction:?} gives {on_screen:?}");
+                assert_point_eq(camera.view_center(), center);
+            }
+        }
+
+        let mut camera = Camera::default();
+        camera.set_orientation(Orientation::YUp);
+        camera.look_direction((0., 1.));
+        assert!(camera.rotation.abs() < 1e-9);
+    }
+
+    #[test]
+    fn look_at_points_the_view_at_the_point() {
+        let mut camera = Camera::default();
+        let center = camera.view_center();
+        let target = Point::new(center.x + 300., center.y + 400.);
+
+        camera.look_at(target);
+        let on_screen = camera.world_to_screen_coords(target);
+        assert!((on_screen.x - 960.).abs() < EPSILON);
+        assert!(on_screen.y < 540.);
+    }
+
+    #[test]
+    fn follow_bounded_eases_into_the_edge() {
+        let mut camera = Camera::default();
+        camera.set_bounds(Rect::new(Point::ZERO, Vec2::new(4000., 3000.), 0.));
+        camera.set_position((1000., 1000.));
+
+        let mut previous = camera.position;
+        for _ in 0..600 {
+            camera.follow_bounded((-1000., 500.), 0.1, 1. / 60.);
+            // No overshoot, the target outside the bounds is approached from one side
+            assert!(camera.position.x <= previous.x && camera.position.x >= 0.);
+            assert!(camera.position.y <= previous.y && camera.position.y >= 500.);
+            previous = camera.position;
+        }
+        assert_point_eq(camera.position, Point::new(0., 500.));
+    }
+
+    fn assert_vec_eq(a: Vec2, b: Vec2) {
+        assert_point_eq(Point::new(a.x, a.y), Point::new(b.x, b.y));
+    }
+
+    #[test]
+    fn basis_follows_the_rotation() {
+        let mut camera = Camera::default();
+        let (right, up) = camera.basis();
+        assert_vec_eq(right, Vec2::new(1., 0.));
+        assert_vec_eq(up, Vec2::new(0., -1.));
+
+        camera.set_rotation(PI / 2.);
+        let (right, up) = camera.basis();
+        assert_vec_eq(right, Vec2::new(0., -1.));
+        assert_vec_eq(up, Vec2::new(-1., 0.));
+
+        let mut camera = Camera::default();
+        camera.set_orientation(Orientation::YUp);
+        let (_, up) = camera.basis();
+        assert_vec_eq(up, Vec2::new(0., 1.));
+    }
+
+    #[test]
+    fn basis_points_right_and_up_on_screen() {
+        for rotation in [0.3, 2., -2.7] {
+            let camera = Camera::new(
+                (960., 540.),
+                rotation,
+                (1.5, 1.5),
+                (80., 40.),
+                (1920., 1080.),
+            );
+            let (right, up) = camera.basis();
+
+            let right = camera.world_to_screen_vector(right);
+            let up = camera.world_to_screen_vector(up);
+            assert_vec_eq(right, Vec2::new(1.5, 0.));
+            assert_vec_eq(up, Vec2::new(0., -1.5));
+        }
+    }
+
+    #[test]
+    fn recenter_takes_the_given_time() {
+        let mut camera = Camera::default();
+        camera.recenter_over((100., 0.), 1.);
+
+        assert!(!camera.update_recenter(0.25));
+        assert!(!camera.update_recenter(0.25));
+        // Eased out, three quarters of the way at half the time
+        assert!((camera.position.x - 75.).abs() < 1e-9);
+        assert!(!camera.update_recenter(0.25));
+        assert!(camera.update_recenter(0.25));
+        assert_eq!(camera.position, Point::new(100., 0.));
+    }
+
+    #[test]
+    fn update_recenter_without_recenter_is_done() {
+        let mut camera = Camera::default();
+
+        assert!(camera.update_recenter(0.25));
+        assert_eq!(camera.position, Point::ZERO);
+    }
+
+    #[test]
+    fn counter_rotation_keeps_objects_upright() {
+        for rotation in [0.4, PI / 2., 2.8, -1.9] {
+            let camera = Camera::new(
+                (960., 540.),
+                rotation,
+                (1.5, 1.5),
+                (80., 40.),
+                (1920., 1080.),
+            );
+            let widget = Transform {
+                dest: Point::new(100., 50.),
+                rotation: camera.counter_rotation(),
+                ..Default::default()
+            };
+
+            let on_screen = Transform::from_matrix(camera.apply_matrix(widget));
+            assert!(
+                on_screen.rotation.abs() < 1e-4,
+                "{rotation} gives {}",
+                on_screen.rotation
+            );
+        }
+    }
+
+    #[test]
+    fn offset_channels_add_up() {
+        let mut camera = Camera::default();
+
+        assert!(camera.set_offset_channel("lean", (10., 0.)));
+        assert!(camera.set_offset_channel("recoil", (0., 5.)));
+        assert_point_eq(camera.origin_on_screen(), Point::new(10., 5.));
+
+        camera.clear_offset_channel("lean");
+        assert_eq!(camera.offset_channel("lean"), None);
+        assert_point_eq(camera.origin_on_screen(), Point::new(0., 5.));
+    }
+
+    #[test]
+    fn offset_channels_are_limited() {
+        const NAMES: [&str; OFFSET_CHANNELS] = ["a", "b", "c", "d", "e", "f", "g", "h"];
+        let mut camera = Camera::default();
+
+        for name in NAMES {
+            assert!(camera.set_offset_channel(name, (1., 0.)));
+        }
+        assert!(!camera.set_offset_channel("one too many", (1., 0.)));
+        assert_point_eq(camera.origin_on_screen(), Point::new(8., 0.));
+
+        // Existing channels can still be updated
+        assert!(camera.set_offset_channel("a", (2., 0.)));
+        assert_point_eq(camera.origin_on_screen(), Point::new(9., 0.));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn offset_channels_arent_serialized() {
+        let mut camera = Camera::default();
+        assert!(camera.set_offset_channel("lean", (10., 0.)));
+
+        let json = serde_json::to_string(&camera).unwrap();
+        let deserialized: Camera = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.offset_channel("lean"), None);
+        assert_point_eq(deserialized.origin_on_screen(), Point::ZERO);
+    }
+
+    #[test]
+    fn clamped_conversion_rejects_the_pillarbox_bars() {
+        let mut camera = Camera::default();
+        camera.set_design_resolution((1920., 1080.));
+        camera.set_screen_size((2560., 1080.));
+
+        assert_eq!(camera.screen_to_world_coords_clamped((100., 540.)), None);
+        assert_eq!(camera.screen_to_world_coords_clamped((2500., 540.)), None);
+        assert_point_eq(
+            camera
+                .screen_to_world_coords_clamped((1280., 540.))
+                .unwrap(),
+            Point::new(960., 540.),
+        );
+    }
+
+    #[test]
+    fn clamped_conversion_rejects_the_letterbox_bars() {
+        let mut camera = Camera::default();
+        camera.set_design_resolution((1920., 1080.));
+        camera.set_screen_size((1920., 1440.));
+
+        assert_eq!(camera.screen_to_world_coords_clamped((960., 50.)), None);
+        assert_eq!(camera.screen_to_world_coords_clamped((960., 1400.)), None);
+        assert_point_eq(
+            camera.screen_to_world_coords_clamped((960., 720.)).unwrap(),
+            Point::new(960., 540.),
+        );
+    }
+
+    #[test]
+    fn smooth_fit_rect_ends_on_fit_rect() {
+        let rect = Rect::new(Point::new(1000., 1000.), Vec2::new(960., 540.), 0.);
+        let mut camera = Camera::default();
+        let start = camera;
+        let mut expected = camera;
+        expected.fit_rect(rect, 0.);
+
+        camera.smooth_fit_rect(&start, rect, 0., 0., Easing::Linear);
+        assert!(camera.approx_eq(&Camera::default(), 1e-9));
+
+        camera.smooth_fit_rect(&start, rect, 0., 0.5, Easing::Linear);
+        assert_point_eq(camera.position, Point::new(500., 500.));
+        assert!((camera.scale.x - 1.5).abs() < 1e-9);
+
+        camera.smooth_fit_rect(&start, rect, 0., 1., Easing::Linear);
+        assert!(camera.approx_eq(&expected, EPSILON));
+        assert_point_eq(camera.view_center(), rect.center());
+    }
+
+    #[test]
+    fn smooth_fit_rect_restarted_midway_starts_where_it_is() {
+        let first = Rect::new(Point::new(1000., 1000.), Vec2::new(960., 540.), 0.);
+        let second = Rect::new(Point::new(-500., 0.), Vec2::new(1920., 1080.), 0.);
+        let mut camera = Camera::default();
+        let start = camera;
+        camera.smooth_fit_rect(&start, first, 0., 0.5, Easing::Linear);
+
+        // Abandoned for another rect, the next transition starts from the current camera
+        let start = camera;
+        camera.smooth_fit_rect(&start, second, 0., 0.1, Easing::Linear);
+        assert!((camera.scale.x - 1.45).abs() < 1e-9);
+    }
+
+    #[test]
+    fn smooth_fit_rect_respects_the_zoom_limits() {
+        let rect = Rect::new(Point::new(1000., 1000.), Vec2::new(480., 270.), 0.);
+        let mut camera = Camera::default();
+        camera.set_zoom_limits(None, Some(Vec2::new(2., 2.)));
+        let start = camera;
+
+        for step in 0..=10 {
+            camera.smooth_fit_rect(
+                &start,
+                rect,
+                0.,
+                step as f64 / 10.,
+                crate::easing::elastic_out,
+            );
+            assert!(
+                camera.scale.x <= 2. && camera.scale.y <= 2.,
+                "{:?}",
+                camera.scale
+            );
+        }
+        assert_eq!(camera.scale, Vec2::new(2., 2.));
+    }
+
+    #[test]
+    fn effective_scale_includes_the_fit_and_the_dpi() {
+        let mut camera = Camera::default();
+        camera.set_zoom((2., 2.));
+        assert_eq!(camera.effective_scale(), Vec2::new(2., 2.));
+
+        camera.set_design_resolution((960., 540.));
+        assert_eq!(camera.effective_scale(), Vec2::new(4., 4.));
+        assert_eq!(camera.get_zoom(), Vec2::new(2., 2.));
+
+        camera.set_dpi_scale(2.);
+        assert_eq!(camera.effective_scale(), Vec2::new(8., 8.));
+    }
+
+    #[test]
+    fn round_trip_world_grid() {
+        for zoom in [0.1, 1., 4., 13.7] {
+            for rotation in [0., 0.3, PI / 2., 2.5, -1.2] {
+                let camera = Camera::new(
+                    (960., 540.),
+                    rotation,
+                    (zoom, zoom),
+                    (123.4, -56.7),
+                    (1920., 1080.),
+                );
+
+                for x in (-2000..=2000).step_by(250) {
+                    for y in (-2000..=2000).step_by(250) {
+                        let world = Point::new(x as f64, y as f64);
+                        assert_point_eq(camera.round_trip_world(world), world);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn y_up_mirrors_the_screen_y() {
+        let y_down = Camera::new((960., 540.), 0.4, (1.5, 1.5), (80., 40.), (1920., 1080.));
+        let mut y_up = y_down;
+        y_up.set_orientation(Orientation::YUp);
+
+        for world in [
+            Point::ZERO,
+            Point::new(300., -200.),
+            Point::new(-750., 420.),
+        ] {
+            let down = y_down.world_to_screen_coords(world);
+            assert_point_eq(
+                y_up.world_to_screen_coords(world),
+                Point::new(down.x, 1080. - down.y),
+            );
+            assert_point_eq(y_up.round_trip_world(world), world);
+        }
+    }
+
+    #[test]
+    fn ensure_visible_brings_the_point_inside_the_margin() {
+        let cases = [
+            (Point::new(-10., 540.), Point::new(50., 540.)),
+            (Point::new(1930., 540.), Point::new(1870., 540.)),
+            (Point::new(960., -10.), Point::new(960., 50.)),
+            (Point::new(960., 1090.), Point::new(960., 1030.)),
+            // On screen but within the margin
+            (Point::new(30., 540.), Point::new(50., 540.)),
+        ];
+
+        for (world, on_screen) in cases {
+            let mut camera = Camera::default();
+            camera.ensure_visible(world, 50.);
+            assert_point_eq(camera.world_to_screen_coords(world), on_screen);
+        }
+    }
+
+    #[test]
+    fn ensure_visible_leaves_visible_points_alone() {
+        let mut camera = Camera::default();
+
+        camera.ensure_visible((960., 540.), 50.);
+        assert_eq!(camera.position, Point::ZERO);
+    }
+
+    #[test]
+    fn display_is_compact() {
+        let camera = Camera::new((0., 0.), PI / 4., (2., 2.), (10., -5.), (1920., 1080.));
+        assert_eq!(
+            camera.to_string(),
+            "Camera(pos=(10.0,-5.0) zoom=2.0 rot=45° screen=1920x1080)"
+        );
+
+        let camera = Camera::new((0., 0.), 0., (2., 3.), (10., -5.), (1920., 1080.));
+        assert_eq!(
+            camera.to_string(),
+            "Camera(pos=(10.0,-5.0) zoom=(2.0,3.0) rot=0° screen=1920x1080)"
+        );
+    }
+
+    #[test]
+    fn cull_keeps_the_visible_items_in_order() {
+        let items = (0..100)
+            .map(|index| Point::new((index % 10) as f64 * 300., (index / 10) as f64 * 300.))
+            .collect::<Vec<_>>();
+        let camera = Camera::default();
+
+        // Columns 0..=6 and rows 0..=3 are within 1920x1080
+        let expected = (0..100)
+            .filter(|index| index % 10 <= 6 && index / 10 <= 3)
+            .collect::<Vec<usize>>();
+        assert_eq!(expected.len(), 28);
+
+        let visible = camera.cull(&items, |point| *point);
+        assert_eq!(
+            visible,
+            expected
+                .iter()
+                .map(|index| &items[*index])
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(camera.cull_indices(&items, |point| *point), expected);
     }
 }
@@ -0,0 +1,127 @@
+use std::f64::consts::TAU;
+
+// A curve mapping a progress in 0..1 to the eased progress, the input is always clamped
+// to 0..1 before being passed in. Implemented for Easing and any Fn(f64) -> f64
+pub trait EasingFn {
+    fn ease(&self, t: f64) -> f64;
+}
+
+impl<F> EasingFn for F
+where
+    F: Fn(f64) -> f64,
+{
+    fn ease(&self, t: f64) -> f64 {
+        self(t)
+    }
+}
+
+impl EasingFn for Easing {
+    fn ease(&self, t: f64) -> f64 {
+        self.apply(t)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+}
+
+impl Easing {
+    // Maps a progress in 0..1 to the eased progress, 0 and 1 are kept as is
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0., 1.);
+
+        match self {
+            Easing::Linear => linear(t),
+            Easing::EaseIn => ease_in(t),
+            Easing::EaseOut => ease_out(t),
+            Easing::EaseInOut => ease_in_out(t),
+        }
+    }
+}
+
+pub fn linear(t: f64) -> f64 {
+    t
+}
+
+pub fn ease_in(t: f64) -> f64 {
+    t * t
+}
+
+pub fn ease_out(t: f64) -> f64 {
+    1. - (1. - t) * (1. - t)
+}
+
+pub fn ease_in_out(t: f64) -> f64 {
+    if t < 0.5 {
+        2. * t * t
+    } else {
+        1. - (-2. * t + 2.).powi(2) / 2.
+    }
+}
+
+// Bounces three times before settling on 1
+pub fn bounce_out(t: f64) -> f64 {
+    const N: f64 = 7.5625;
+    const D: f64 = 2.75;
+
+    if t < 1. / D {
+        N * t * t
+    } else if t < 2. / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+// Overshoots 1 and oscillates around it while settling
+pub fn elastic_out(t: f64) -> f64 {
+    if t <= 0. || t >= 1. {
+        return t;
+    }
+
+    2f64.powf(-10. * t) * ((t * 10. - 0.75) * (TAU / 3.)).sin() + 1.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eased<E: EasingFn>(easing: E, t: f64) -> f64 {
+        easing.ease(t)
+    }
+
+    #[test]
+    fn functions_and_closures_are_curves() {
+        assert_eq!(eased(Easing::EaseIn, 0.5), Easing::EaseIn.apply(0.5));
+        assert_eq!(eased(ease_out, 0.5), 0.75);
+        assert_eq!(eased(|t: f64| 1. - t, 0.25), 0.75);
+    }
+
+    #[test]
+    fn builtin_curves_keep_the_endpoints() {
+        let curves: [fn(f64) -> f64; 6] = [
+            linear,
+            ease_in,
+            ease_out,
+            ease_in_out,
+            bounce_out,
+            elastic_out,
+        ];
+
+        for curve in curves {
+            assert!(curve(0.).abs() < 1e-9);
+            assert!((curve(1.) - 1.).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// Maps the speed of the followed target to a zoom, used by Camera::follow_dynamic.
+// Smoothing values are the fraction of the remaining distance covered per 1/60s step
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DynamicFollowConfig {
+    // World units per second, below min_speed the zoom is max_zoom
+    pub min_speed: f64,
+    // World units per second, above max_speed the zoom is min_zoom
+    pub max_speed: f64,
+    pub min_zoom: f64,
+    pub max_zoom: f64,
+    pub follow_smoothing: f64,
+    pub zoom_smoothing: f64,
+}
+
+impl Default for DynamicFollowConfig {
+    fn default() -> Self {
+        DynamicFollowConfig {
+            min_speed: 0.,
+            max_speed: 1000.,
+            min_zoom: 0.5,
+            max_zoom: 1.,
+            follow_smoothing: 0.1,
+            zoom_smoothing: 0.05,
+        }
+    }
+}
+
+impl DynamicFollowConfig {
+    // Linear from max_zoom at min_speed to min_zoom at max_speed
+    pub fn zoom_for_speed(&self, speed: f64) -> f64 {
+        let range = self.max_speed - self.min_speed;
+        let t = if range <= 0. {
+            if speed > self.min_speed {
+                1.
+            } else {
+                0.
+            }
+        } else {
+            ((speed - self.min_speed) / range).clamp(0., 1.)
+        };
+
+        self.max_zoom + (self.min_zoom - self.max_zoom) * t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zoom_for_speed_goes_from_max_to_min_zoom() {
+        let config = DynamicFollowConfig::default();
+
+        assert_eq!(config.zoom_for_speed(0.), 1.);
+        assert_eq!(config.zoom_for_speed(500.), 0.75);
+        assert_eq!(config.zoom_for_speed(1000.), 0.5);
+        assert_eq!(config.zoom_for_speed(5000.), 0.5);
+    }
+
+    #[test]
+    fn zoom_for_speed_with_an_empty_range_is_a_step() {
+        let config = DynamicFollowConfig {
+            min_speed: 100.,
+            max_speed: 100.,
+            ..Default::default()
+        };
+
+        assert_eq!(config.zoom_for_speed(100.), 1.);
+        assert_eq!(config.zoom_for_speed(100.5), 0.5);
+    }
+}
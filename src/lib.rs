@@ -1,6 +1,57 @@
+pub mod builder;
 pub mod camera;
+pub mod easing;
+pub mod follow;
+pub mod shake;
 pub mod transform;
+pub mod tween;
 
+pub use builder::*;
 pub use camera::*;
+pub use easing::*;
+pub use follow::*;
+pub use shake::*;
 pub use transform::*;
+pub use tween::*;
 
+#[cfg(test)]
+mod tests {
+    // Only the core math, so it keeps passing with --no-default-features
+    use super::*;
+    use glam::Vec3;
+    use maths::Point;
+
+    #[test]
+    fn camera_math_without_ggez() {
+        let camera = CameraBuilder::new()
+            .offset((640., 360.))
+            .rotation(0.4)
+            .scale((2., 2.))
+            .position((25., -10.))
+            .screen_size((1280., 720.))
+            .build();
+
+        let world = Point::new(100., 50.);
+        let screen = camera.world_to_screen_coords(world);
+        let projected = camera
+            .to_matrix()
+            .transform_point3(Vec3::new(100., 50., 0.));
+        assert!((screen.x - projected.x as f64).abs() < 1e-3);
+        assert!((screen.y - projected.y as f64).abs() < 1e-3);
+
+        let back = camera.screen_to_world_coords(screen);
+        assert!((back.x - world.x).abs() < 1e-2 && (back.y - world.y).abs() < 1e-2);
+    }
+
+    #[test]
+    fn transform_math_without_ggez() {
+        let camera = Camera::default();
+        let object = Transform {
+            dest: Point::new(30., 40.),
+            ..Default::default()
+        };
+
+        assert_eq!(camera.apply_matrix(object), object.to_matrix());
+        assert!(Transform::from_matrix(object.to_matrix()) == object);
+    }
+}
@@ -0,0 +1,244 @@
+use maths::Point;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShakeConfig {
+    // Noise samples per second
+    pub frequency: f64,
+    // Offset in pixels at full trauma
+    pub max_offset: f64,
+    // Rotation in radians at full trauma
+    pub max_angle: f64,
+    // Trauma lost per second
+    pub decay: f64,
+}
+
+impl Default for ShakeConfig {
+    fn default() -> Self {
+        ShakeConfig {
+            frequency: 15.,
+            max_offset: 20.,
+            max_angle: 0.,
+            decay: 1.,
+        }
+    }
+}
+
+// Peak offsets are reached at full trauma, a full trauma shake lasts 1 / decay seconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ShakePreset {
+    // Up to 6 pixels and 0.02 radians, lasts 0.5s
+    Small,
+    // Up to 15 pixels and 0.05 radians, lasts 0.75s
+    Medium,
+    // Up to 35 pixels and 0.1 radians, lasts 1s
+    Large,
+}
+
+impl From<ShakePreset> for ShakeConfig {
+    fn from(value: ShakePreset) -> Self {
+        match value {
+            ShakePreset::Small => ShakeConfig {
+                frequency: 20.,
+                max_offset: 6.,
+                max_angle: 0.02,
+                decay: 2.,
+            },
+            ShakePreset::Medium => ShakeConfig {
+                frequency: 15.,
+                max_offset: 15.,
+                max_angle: 0.05,
+                decay: 1. / 0.75,
+            },
+            ShakePreset::Large => ShakeConfig {
+                frequency: 12.,
+                max_offset: 35.,
+                max_angle: 0.1,
+                decay: 1.,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShakeState {
+    pub config: ShakeConfig,
+    pub trauma: f64,
+    pub seed: u64,
+    time: f64,
+}
+
+impl ShakeState {
+    pub fn add_trauma(&mut self, trauma: f64) {
+        self.trauma = (self.trauma + trauma).clamp(0., 1.);
+    }
+
+    pub fn update(&mut self, dt: f64) {
+        self.trauma = (self.trauma - self.config.decay * dt).max(0.);
+        if self.trauma == 0. {
+            self.time = 0.;
+        } else {
+            self.time += dt;
+        }
+    }
+
+    // Screen space offset, None when there is no trauma left so callers can skip it entirely
+    pub fn offset(&self) -> Option<Point> {
+        if self.trauma <= 0. {
+            return None;
+        }
+
+        Some(sample_offset(
+            self.seed,
+            self.trauma,
+            self.time,
+            &self.config,
+        ))
+    }
+
+    // Rotation offset, None when there is no trauma left
+    pub fn rotation(&self) -> Option<f64> {
+        if self.trauma <= 0. {
+            return None;
+        }
+
+        Some(sample_rotation(
+            self.seed,
+            self.trauma,
+            self.time,
+            &self.config,
+        ))
+    }
+}
+
+// (offset, rotation) of a shake driven from outside, `time` being the seconds since it started.
+// Same as a ShakeState with the default seed holding that trauma and time
+pub fn shake_offset(trauma: f64, time: f64, config: &ShakeConfig) -> (Point, f64) {
+    let trauma = trauma.clamp(0., 1.);
+    if trauma == 0. {
+        return (Point::ZERO, 0.);
+    }
+
+    (
+        sample_offset(0, trauma, time, config),
+        sample_rotation(0, trauma, time, config),
+    )
+}
+
+fn sample_offset(seed: u64, trauma: f64, time: f64, config: &ShakeConfig) -> Point {
+    let amount = config.max_offset * trauma.powi(2);
+    let t = time * config.frequency;
+
+    Point::new(noise(seed, 0, t) * amount, noise(seed, 1, t) * amount)
+}
+
+// Sampled from its own noise channel so it doesn't follow the offset
+fn sample_rotation(seed: u64, trauma: f64, time: f64, config: &ShakeConfig) -> f64 {
+    let amount = config.max_angle * trauma.powi(2);
+
+    noise(seed, 2, time * config.frequency) * amount
+}
+
+// Smoothed value noise in -1..1, hashed from the inputs rather than drawn from
+// a global rng so it's deterministic across runs and platforms
+fn noise(seed: u64, channel: u64, t: f64) -> f64 {
+    let i = t.floor();
+    let f = t - i;
+    let f = f * f * (3. - 2. * f);
+
+    let a = lattice(seed, channel, i as i64);
+    let b = lattice(seed, channel, i as i64 + 1);
+
+    a + (b - a) * f
+}
+
+fn lattice(seed: u64, channel: u64, i: i64) -> f64 {
+    let x = splitmix64(
+        seed ^ channel.wrapping_mul(0xD1B5_4A32_D192_ED03)
+            ^ (i as u64).wrapping_mul(0xAEF1_7502_108E_F2D9),
+    );
+
+    (x >> 11) as f64 / (1u64 << 53) as f64 * 2. - 1.
+}
+
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_peak_within_their_range_and_decay() {
+        // (preset, max offset, max angle, duration) as documented on ShakePreset
+        let presets = [
+            (ShakePreset::Small, 6., 0.02, 0.5),
+            (ShakePreset::Medium, 15., 0.05, 0.75),
+            (ShakePreset::Large, 35., 0.1, 1.),
+        ];
+
+        for (preset, max_offset, max_angle, duration) in presets {
+            let mut shake = ShakeState {
+                config: preset.into(),
+                ..Default::default()
+            };
+            shake.add_trauma(1.);
+
+            let dt = 1. / 120.;
+            let mut elapsed: f64 = 0.;
+            let mut peak: f64 = 0.;
+            while elapsed < duration * 0.9 {
+                let offset = shake.offset().unwrap();
+                let angle = shake.rotation().unwrap();
+                assert!(offset.x.abs() <= max_offset && offset.y.abs() <= max_offset);
+                assert!(angle.abs() <= max_angle);
+                peak = peak.max(offset.x.abs()).max(offset.y.abs());
+
+                shake.update(dt);
+                elapsed += dt;
+            }
+            assert!(peak > 0.);
+
+            while elapsed < duration + 0.05 {
+                shake.update(dt);
+                elapsed += dt;
+            }
+            assert_eq!(shake.trauma, 0.);
+            assert!(shake.offset().is_none() && shake.rotation().is_none());
+        }
+    }
+
+    #[test]
+    fn shake_offset_matches_the_state() {
+        let config = ShakeConfig {
+            max_angle: 0.1,
+            ..Default::default()
+        };
+        let state = ShakeState {
+            config,
+            trauma: 0.7,
+            time: 1.3,
+            ..Default::default()
+        };
+
+        let (offset, rotation) = shake_offset(0.7, 1.3, &config);
+        assert_eq!(Some(offset), state.offset());
+        assert_eq!(Some(rotation), state.rotation());
+        // Deterministic, the same inputs give the same shake
+        assert_eq!(shake_offset(0.7, 1.3, &config), (offset, rotation));
+    }
+
+    #[test]
+    fn shake_offset_without_trauma_is_zero() {
+        assert_eq!(
+            shake_offset(0., 1.3, &ShakeConfig::default()),
+            (Point::ZERO, 0.)
+        );
+    }
+}
@@ -1,10 +1,10 @@
-use ggez::{
-    glam::Mat4,
-    graphics::{self, DrawParam},
-};
+#[cfg(feature = "ggez")]
+use ggez::graphics::{self, DrawParam};
+use glam::Mat4;
 use maths::{Point, Vec2};
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transform {
     pub dest: Point,
     pub rotation: f64,
@@ -33,6 +33,37 @@ impl Transform {
         .transpose()
     }
 
+    // 2D affine decomposition, the offset can't be told apart from dest so it is left at zero
+    pub fn from_matrix(matrix: Mat4) -> Self {
+        let x_axis = matrix.x_axis;
+        let y_axis = matrix.y_axis;
+
+        let scale_x = (x_axis.x as f64).hypot(x_axis.y as f64);
+        let mut scale_y = (y_axis.x as f64).hypot(y_axis.y as f64);
+        if x_axis.x * y_axis.y - x_axis.y * y_axis.x < 0. {
+            scale_y = -scale_y;
+        }
+
+        let rotation = if scale_x == 0. {
+            0.
+        } else {
+            (x_axis.y as f64).atan2(x_axis.x as f64)
+        };
+
+        Transform {
+            dest: Point::new(matrix.w_axis.x as f64, matrix.w_axis.y as f64),
+            rotation,
+            scale: Vec2::new(scale_x, scale_y),
+            offset: Point::ZERO,
+        }
+    }
+
+    // Parent then child, decomposed back into a Transform. Non uniform scale combined with
+    // rotation can shear, which a Transform can't hold, the shear is then dropped
+    pub fn compose(&self, child: &Transform) -> Transform {
+        Transform::from_matrix(self.to_matrix().mul_mat4(&child.to_matrix()))
+    }
+
     pub fn apply_matrix(&self, parent_matrix: &Mat4) -> Mat4 {
         parent_matrix.mul_mat4(&self.to_matrix())
     }
@@ -49,12 +80,14 @@ impl Default for Transform {
     }
 }
 
+#[cfg(feature = "ggez")]
 impl From<Transform> for DrawParam {
     fn from(value: Transform) -> Self {
         DrawParam::default().transform(value.to_matrix())
     }
 }
 
+#[cfg(feature = "ggez")]
 impl From<graphics::Transform> for Transform {
     fn from(value: graphics::Transform) -> Self {
         match value {
@@ -69,9 +102,183 @@ impl From<graphics::Transform> for Transform {
                 scale: scale.into(),
                 offset: offset.into(),
             },
-            graphics::Transform::Matrix(_) => {
-                panic!("Cannot convert ggez::Transform to crate::Transform")
-            }
+            graphics::Transform::Matrix(matrix) => Transform::from_matrix(matrix.into()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let transform = Transform {
+            dest: Point::new(10., -20.),
+            rotation: 1.25,
+            scale: Vec2::new(2., 0.5),
+            offset: Point::new(3., 4.),
+        };
+
+        let json = serde_json::to_string(&transform).unwrap();
+        let deserialized: Transform = serde_json::from_str(&json).unwrap();
+
+        assert!(deserialized == transform);
+    }
+
+    #[test]
+    fn partial_eq_compares_every_field() {
+        let transform = Transform {
+            dest: Point::new(1., 2.),
+            ..Default::default()
+        };
+
+        let copy = transform;
+        assert!(copy == transform);
+        assert!(transform != Transform::default());
+        assert!(
+            transform
+                != Transform {
+                    offset: Point::new(0.5, 0.),
+                    ..transform
+                }
+        );
+    }
+
+    fn assert_transform_eq(a: &Transform, b: &Transform) {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-4;
+
+        assert!(close(a.dest.x, b.dest.x) && close(a.dest.y, b.dest.y));
+        assert!(close(a.rotation, b.rotation));
+        assert!(close(a.scale.x, b.scale.x) && close(a.scale.y, b.scale.y));
+        assert!(close(a.offset.x, b.offset.x) && close(a.offset.y, b.offset.y));
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        let transform = Transform {
+            dest: Point::new(12., -7.),
+            rotation: 0.6,
+            scale: Vec2::new(2., 0.5),
+            offset: Point::ZERO,
+        };
+
+        assert_transform_eq(&Transform::from_matrix(transform.to_matrix()), &transform);
+    }
+
+    #[test]
+    fn matrix_round_trip_with_a_flipped_axis() {
+        let transform = Transform {
+            dest: Point::new(-3., 40.),
+            rotation: -1.1,
+            scale: Vec2::new(1.5, -2.),
+            offset: Point::ZERO,
+        };
+
+        assert_transform_eq(&Transform::from_matrix(transform.to_matrix()), &transform);
+    }
+
+    #[test]
+    fn zero_scale_matrix_has_no_rotation() {
+        let transform = Transform {
+            rotation: 1.,
+            scale: Vec2::new(0., 1.),
+            ..Default::default()
+        };
+
+        assert_eq!(Transform::from_matrix(transform.to_matrix()).rotation, 0.);
+    }
+
+    #[cfg(feature = "ggez")]
+    #[test]
+    fn ggez_matrix_transform_is_decomposed() {
+        let transform = Transform {
+            dest: Point::new(100., 50.),
+            rotation: 0.25,
+            scale: Vec2::new(3., 3.),
+            offset: Point::ZERO,
+        };
+
+        let decomposed = Transform::from(graphics::Transform::Matrix(transform.to_matrix().into()));
+
+        assert_transform_eq(&decomposed, &transform);
+    }
+
+    fn assert_compose_matches_the_product(parent: &Transform, child: &Transform) -> Transform {
+        let composed = parent.compose(child);
+        let product = parent.to_matrix().mul_mat4(&child.to_matrix());
+
+        assert!(composed.to_matrix().abs_diff_eq(product, 1e-4));
+        composed
+    }
+
+    #[test]
+    fn compose_translations() {
+        let parent = Transform {
+            dest: Point::new(10., 5.),
+            ..Default::default()
+        };
+        let child = Transform {
+            dest: Point::new(3., 4.),
+            ..Default::default()
+        };
+
+        let composed = assert_compose_matches_the_product(&parent, &child);
+        assert_transform_eq(
+            &composed,
+            &Transform {
+                dest: Point::new(13., 9.),
+                ..Default::default()
+            },
+        );
+    }
+
+    #[test]
+    fn compose_rotations() {
+        let parent = Transform {
+            rotation: 0.3,
+            ..Default::default()
+        };
+        let child = Transform {
+            rotation: 0.4,
+            ..Default::default()
+        };
+
+        let composed = assert_compose_matches_the_product(&parent, &child);
+        assert_transform_eq(
+            &composed,
+            &Transform {
+                rotation: 0.7,
+                ..Default::default()
+            },
+        );
+    }
+
+    #[test]
+    fn compose_uniform_scales() {
+        let parent = Transform {
+            dest: Point::new(100., 0.),
+            rotation: 0.5,
+            scale: Vec2::new(2., 2.),
+            offset: Point::ZERO,
+        };
+        let child = Transform {
+            dest: Point::new(10., 0.),
+            scale: Vec2::new(3., 3.),
+            ..Default::default()
+        };
+
+        let composed = assert_compose_matches_the_product(&parent, &child);
+        let (sinr, cosr) = 0.5f64.sin_cos();
+        assert_transform_eq(
+            &composed,
+            &Transform {
+                dest: Point::new(100. + 20. * cosr, 20. * sinr),
+                rotation: 0.5,
+                scale: Vec2::new(6., 6.),
+                offset: Point::ZERO,
+            },
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use super::{
+    camera::Camera,
+    easing::{Easing, EasingFn},
+};
+
+// Drives a transition between two cameras over time, fields lerp doesn't blend come from start
+// until the tween is finished, it then gives end as is.
+// The easing is an Easing by default but any EasingFn works, closures included
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraTween<E = Easing> {
+    pub start: Camera,
+    pub end: Camera,
+    // In seconds, a zero duration jumps straight to the end
+    pub duration: f64,
+    pub easing: E,
+    elapsed: f64,
+}
+
+impl<E> CameraTween<E>
+where
+    E: EasingFn,
+{
+    pub fn new(start: Camera, end: Camera, duration: f64, easing: E) -> Self {
+        CameraTween {
+            start,
+            end,
+            duration,
+            easing,
+            elapsed: 0.,
+        }
+    }
+
+    // Advances the tween and gives the blended camera, stays on the end camera once finished
+    pub fn update(&mut self, dt: f64) -> Camera {
+        self.elapsed = (self.elapsed + dt).min(self.duration.max(0.));
+        self.camera()
+    }
+
+    // The eased progress isn't clamped, so curves overshooting 1 like elastic_out
+    // overshoot the end camera too
+    pub fn camera(&self) -> Camera {
+        if self.is_finished() {
+            return self.end;
+        }
+
+        Camera::blend(&self.start, &self.end, self.easing.ease(self.progress()))
+    }
+
+    // Linear progress in 0..1, before easing
+    pub fn progress(&self) -> f64 {
+        if self.duration <= 0. {
+            return 1.;
+        }
+
+        (self.elapsed / self.duration).clamp(0., 1.)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.
+    }
+
+    pub fn restart(&mut self) {
+        self.elapsed = 0.;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{camera::Orientation, easing::elastic_out};
+    use maths::{Point, Rect, Vec2};
+
+    fn cameras() -> (Camera, Camera) {
+        let start = Camera::default();
+        let mut end = Camera::default();
+        end.set_position((100., 0.));
+
+        (start, end)
+    }
+
+    #[test]
+    fn progress_at_half_duration() {
+        let (start, end) = cameras();
+        let mut tween = CameraTween::new(start, end, 2., Easing::Linear);
+
+        let camera = tween.update(1.);
+        assert_eq!(tween.progress(), 0.5);
+        assert!(!tween.is_finished());
+        assert!((camera.position.x - 50.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn completion_is_detected() {
+        let (start, end) = cameras();
+        let mut tween = CameraTween::new(start, end, 2., Easing::EaseInOut);
+
+        tween.update(1.5);
+        assert!(!tween.is_finished());
+        assert_eq!(tween.update(0.5), end);
+        assert!(tween.is_finished());
+        // Stays on the end camera
+        assert_eq!(tween.update(5.), end);
+
+        tween.restart();
+        assert!(!tween.is_finished());
+        assert_eq!(tween.camera(), start);
+    }
+
+    #[test]
+    fn finishes_on_the_whole_end_camera() {
+        let (start, mut end) = cameras();
+        end.set_orientation(Orientation::YUp);
+        end.set_bounds(Rect::new(Point::ZERO, Vec2::new(5000., 5000.), 0.));
+        let mut tween = CameraTween::new(start, end, 1., Easing::Linear);
+
+        assert_eq!(tween.update(0.5).orientation, Orientation::YDown);
+        assert_eq!(tween.update(0.5), end);
+    }
+
+    #[test]
+    fn zero_duration_finishes_right_away() {
+        let (start, end) = cameras();
+        let tween = CameraTween::new(start, end, 0., Easing::Linear);
+
+        assert!(tween.is_finished());
+        assert_eq!(tween.camera(), end);
+    }
+
+    #[test]
+    fn custom_curve_is_applied() {
+        let (start, end) = cameras();
+        let mut tween = CameraTween::new(start, end, 1., |t: f64| 1. - t);
+
+        // Inverted, a quarter of the way in is three quarters of the way there
+        let camera = tween.update(0.25);
+        assert!((camera.position.x - 75.).abs() < 1e-9);
+        let camera = tween.update(0.5);
+        assert!((camera.position.x - 25.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn overshooting_curves_overshoot_the_end() {
+        let (start, end) = cameras();
+        let mut tween = CameraTween::new(start, end, 1., elastic_out);
+
+        // elastic_out(0.2) is 1.125
+        let camera = tween.update(0.2);
+        assert!((camera.position.x - 112.5).abs() < 1e-6);
+        assert_eq!(tween.update(0.8), end);
+    }
+}